@@ -122,9 +122,9 @@ int main(int argc, char *argv[]) {
 
     char* path;
     if (strcmp(kind, "examples") == 0) {
-        path = "../data/examples/03.txt";
+        path = "../data/2025/examples/03.txt";
     } else if (strcmp(kind, "input") == 0) {
-        path = "../data/inputs/03.txt";
+        path = "../data/2025/inputs/03.txt";
     } else {
         printf("unknown kind: %s\n", kind);
         return 1;
@@ -69,7 +69,7 @@ end
 
 defmodule Day04 do
   def load_file() do
-    File.read!("../data/inputs/04.txt")
+    File.read!("../data/2025/inputs/04.txt")
     |> PaperMap.parse()
   end
 end
@@ -112,7 +112,7 @@ local function run(part, input_kind)
 		os.exit(1)
 	end
 
-	local path = "../data/" .. input_kind .. "/" .. DAY .. ".txt"
+	local path = "../data/2025/" .. input_kind .. "/" .. DAY .. ".txt"
 	local contents = read_or_panic(path)
 
 	local result = part(contents)
@@ -67,12 +67,12 @@ def part2(input)
     .sum()
 end
 
-example = Input.new(File.read("../data/examples/05.txt"))
+example = Input.new(File.read("../data/2025/examples/05.txt"))
 puts("example part 1: #{part1(example)}")
 puts("example part 2: #{part2(example)}")
 
 puts("----")
 
-input = Input.new(File.read("../data/inputs/05.txt"))
+input = Input.new(File.read("../data/2025/inputs/05.txt"))
 puts("part 1: #{part1(input)}")
 puts("part 2: #{part2(input)}")
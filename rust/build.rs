@@ -0,0 +1,176 @@
+//! Includes every solution binary in `src/bin` as a module of the `aoc-runner` binary,
+//! so that `all` and `time` can run solutions in-process via the solution registry.
+//! Also generates the example tests of every solution, see `example_tests!`.
+use std::{env, fs, path::Path, process::Command};
+
+// NOTE: the build script only resolves the data directory.
+#[allow(dead_code)]
+#[path = "src/template/data.rs"]
+mod data;
+
+// NOTE: the build script only splits the front matter of examples.
+#[allow(dead_code)]
+#[path = "src/template/front_matter.rs"]
+mod front_matter;
+
+fn is_solution_bin(name: &str) -> bool {
+    // Solution binaries are named `<year>-<day>.rs`, e.g. `2025-01.rs`.
+    let bytes = name.as_bytes();
+    bytes.len() == 10
+        && name.ends_with(".rs")
+        && bytes[4] == b'-'
+        && bytes[..4].iter().all(u8::is_ascii_digit)
+        && bytes[5..7].iter().all(u8::is_ascii_digit)
+}
+
+/// Returns the parts that the front matter of an example declares an answer for, and the reason
+/// why their tests are ignored, if any.
+fn declared_parts(contents: &str) -> Result<(Vec<u8>, Option<&str>), String> {
+    let (entries, _) = front_matter::split_front_matter(contents)?;
+
+    let mut parts: Vec<u8> = entries
+        .iter()
+        .filter_map(|(key, _)| match *key {
+            "part_1" => Some(1),
+            "part_2" => Some(2),
+            _ => None,
+        })
+        .collect();
+    parts.sort_unstable();
+    parts.dedup();
+
+    let ignore = entries
+        .iter()
+        .find(|(key, _)| *key == "ignore")
+        .map(|(_, reason)| *reason);
+
+    Ok((parts, ignore))
+}
+
+/// Generates a test for every declared answer of the examples of a solution.
+/// Examples are named `<day>.txt` or `<day>-<name>.txt`.
+fn example_tests(examples_dir: &Path, day: &str) -> String {
+    let mut examples: Vec<_> = fs::read_dir(examples_dir)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .map(|entry| entry.file_name().to_string_lossy().to_string())
+                .filter(|name| {
+                    name.strip_suffix(".txt")
+                        .and_then(|stem| stem.strip_prefix(day))
+                        .is_some_and(|rest| rest.is_empty() || rest.starts_with('-'))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    examples.sort_unstable();
+
+    let mut tests = String::new();
+
+    for example in &examples {
+        let path = examples_dir.join(example);
+        let contents = fs::read_to_string(&path).unwrap_or_default();
+        let stem: String = example
+            .trim_end_matches(".txt")
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+
+        let (parts, ignore) = match declared_parts(&contents) {
+            Ok(declared) => declared,
+            Err(e) => {
+                // NOTE: a malformed front matter fails a test instead of silently skipping it.
+                tests.push_str(&format!(
+                    "#[test]
+                fn example_{stem}() {{
+                        panic!(\"invalid front matter in {{}}: {{}}\", {example:?}, {e:?});
+                }}
+"
+                ));
+                continue;
+            }
+        };
+        let ignore = ignore
+            .map(|reason| format!("#[ignore = {reason:?}]\n"))
+            .unwrap_or_default();
+
+        for part in parts {
+            tests.push_str(&format!(
+                "#[test]
+                {ignore}fn example_{stem}_part_{part}() {{
+                        advent_of_code::template::examples::check({example:?}, include_str!({:?}), {part}, super::solve_example);
+                }}
+",
+                path.display().to_string()
+            ));
+        }
+    }
+
+    tests
+}
+
+/// Returns the version of the compiler, which is stored with benchmark timings.
+fn rustc_version() -> String {
+    let rustc = env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+    Command::new(rustc)
+        .arg("--version")
+        .output()
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .unwrap_or_default()
+}
+
+fn main() {
+    println!("cargo::rustc-env=AOC_RUSTC_VERSION={}", rustc_version());
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+    println!("cargo::rerun-if-changed={}", bin_dir.display());
+
+    let mut bins: Vec<_> = fs::read_dir(&bin_dir)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .map(|entry| entry.file_name().to_string_lossy().to_string())
+                .filter(|name| is_solution_bin(name))
+                .collect()
+        })
+        .unwrap_or_default();
+    bins.sort_unstable();
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let data_dir = data::data_dir();
+    println!("cargo::rerun-if-env-changed={}", data::DATA_DIR_ENV);
+    let tests_dir = Path::new(&out_dir).join("examples");
+    fs::create_dir_all(&tests_dir).unwrap();
+
+    // The runner includes the solutions as modules, their example tests run in their own binaries.
+    fs::write(tests_dir.join("aoc-runner.rs"), "").unwrap();
+
+    let mut modules = String::new();
+    let mut solutions = String::new();
+
+    for bin in &bins {
+        let module = format!("solution_{}", bin.trim_end_matches(".rs").replace('-', "_"));
+        let path = bin_dir.join(bin);
+        modules.push_str(&format!(
+            "#[allow(dead_code)]\n#[path = {:?}]\nmod {module};\n",
+            path.display().to_string()
+        ));
+        solutions.push_str(&format!("    {module}::SOLUTION,\n"));
+
+        let (year, day) = bin.trim_end_matches(".rs").split_once('-').unwrap();
+        let examples_dir = data_dir.join(year).join("examples");
+        if examples_dir.exists() {
+            println!("cargo::rerun-if-changed={}", examples_dir.display());
+        }
+        fs::write(tests_dir.join(bin), example_tests(&examples_dir, day)).unwrap();
+    }
+
+    let contents = format!(
+        "{modules}\n\
+        /// Every solution binary that was present when the runner was built.\n\
+        pub const SOLUTIONS: &[advent_of_code::template::registry::Solution] = &[\n{solutions}];\n"
+    );
+
+    fs::write(Path::new(&out_dir).join("solutions.rs"), contents).unwrap();
+}
@@ -0,0 +1,82 @@
+advent_of_code::solution!(1, year = 2025);
+
+/// Parses a rotation like `L68` into a signed number of clicks.
+fn parse_rotation(line: &str) -> Result<i64, String> {
+    let (dir, num) = line
+        .split_at_checked(1)
+        .ok_or_else(|| format!("invalid rotation `{line}`"))?;
+    let num = num
+        .parse::<i64>()
+        .map_err(|e| format!("invalid rotation `{line}`: {e}"))?;
+
+    match dir {
+        "L" => Ok(-num),
+        "R" => Ok(num),
+        dir => Err(format!("unknown dir `{dir}` in rotation `{line}`")),
+    }
+}
+
+pub fn part_one(input: &str) -> Result<u64, String> {
+    Ok(input
+        .lines()
+        .map(parse_rotation)
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .fold((50, 0), |(mut accum, mut count), dir| {
+            accum = (accum + (dir + 100)) % 100;
+
+            if accum == 0 {
+                count += 1;
+            }
+
+            (accum, count)
+        })
+        .1)
+}
+
+pub fn part_two(input: &str) -> Result<u64, String> {
+    Ok(input
+        .lines()
+        .map(parse_rotation)
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .fold((50i64, 0), |(mut accum, mut count), dir| {
+            // Count full rotations.
+            count += dir.unsigned_abs() / 100;
+
+            let end = accum + (dir % 100);
+
+            if accum != 0 && (end <= 0 || end > 99) {
+                count += 1;
+            }
+
+            accum = (end + 100) % 100;
+
+            (accum, count)
+        })
+        .1)
+}
+
+advent_of_code::example_tests!();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extra() {
+        // assert_eq!(part_two("R1000"), Ok(10));
+        assert_eq!(part_two("L50\nR100"), Ok(2));
+    }
+
+    #[test]
+    fn mini() {
+        assert_eq!(part_two("L68"), Ok(1));
+    }
+
+    #[test]
+    fn malformed() {
+        assert!(part_one("L68\nX3").is_err());
+        assert!(part_two("L6a").is_err());
+    }
+}
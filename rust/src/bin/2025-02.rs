@@ -1,4 +1,4 @@
-advent_of_code::solution!(2);
+advent_of_code::solution!(2, year = 2025);
 
 fn is_repeated(n: u64) -> bool {
     repeated_times(n, 2)
@@ -52,23 +52,13 @@ pub fn part_two(input: &str) -> Option<u64> {
     )
 }
 
+advent_of_code::example_tests!();
+
 #[cfg(test)]
 mod tests {
 
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(1227775554));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(4174379265));
-    }
-
     #[test]
     fn asdfasdf() {
         assert!(!is_repeated(1000));
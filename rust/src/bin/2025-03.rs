@@ -1,4 +1,4 @@
-advent_of_code::solution!(3);
+advent_of_code::solution!(3, year = 2025);
 
 fn largest(batteries: &[u64], remaining: u32) -> u64 {
     if batteries.len() < remaining as usize || remaining == 0 {
@@ -51,19 +51,4 @@ pub fn part_two(input: &str) -> Option<u64> {
     )
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(357));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(3121910778619));
-    }
-}
+advent_of_code::example_tests!();
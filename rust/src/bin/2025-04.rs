@@ -3,7 +3,7 @@ use std::{
     ops::{Index, IndexMut},
 };
 
-advent_of_code::solution!(4);
+advent_of_code::solution!(4, year = 2025);
 
 fn deltas() -> impl Iterator<Item = (isize, isize)> {
     (-1..=1)
@@ -124,19 +124,4 @@ pub fn part_two(input: &str) -> Option<u64> {
     )
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(13));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(43));
-    }
-}
+advent_of_code::example_tests!();
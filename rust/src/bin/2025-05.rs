@@ -1,6 +1,6 @@
 use std::ops::RangeInclusive;
 
-advent_of_code::solution!(5);
+advent_of_code::solution!(5, year = 2025);
 
 fn parse(input: &str) -> (Vec<RangeInclusive<u64>>, Vec<u64>) {
     let (ranges, available) = input.split_once("\n\n").unwrap();
@@ -73,19 +73,4 @@ pub fn part_two(input: &str) -> Option<u64> {
     Some(solutions.into_iter().flatten().count() as u64)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(3));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(14));
-    }
-}
+advent_of_code::example_tests!();
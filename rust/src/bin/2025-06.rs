@@ -1,6 +1,6 @@
 use std::ops::{Deref, Index, Range};
 
-advent_of_code::solution!(6);
+advent_of_code::solution!(6, year = 2025);
 
 #[derive(Clone, Copy, Debug)]
 enum Operation {
@@ -229,19 +229,4 @@ pub fn part_two(input: &str) -> Option<u64> {
     Some(solve(input, true))
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(4277556));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(3263827));
-    }
-}
+advent_of_code::example_tests!();
@@ -1,4 +1,4 @@
-advent_of_code::solution!(7);
+advent_of_code::solution!(7, year = 2025);
 
 pub fn part_one(input: &str) -> Option<u64> {
     let mut lines = input.lines().map(|line| line.as_bytes());
@@ -64,19 +64,4 @@ pub fn part_two(input: &str) -> Option<u64> {
     )
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(21));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(40));
-    }
-}
+advent_of_code::example_tests!();
@@ -1,7 +1,16 @@
-advent_of_code::solution!(8);
+advent_of_code::solution!(8, year = 2025, parse = parse, params = Params);
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let boxes = input
+advent_of_code::params! {
+    pub struct Params {
+        /// The number of closest pairs of junction boxes to connect.
+        pub connections: usize = 1000,
+    }
+}
+
+type JunctionBox = (usize, usize, usize);
+
+pub fn parse(input: &str) -> Vec<JunctionBox> {
+    input
         .lines()
         .map(|line| line.split(','))
         .map(|mut iter| {
@@ -11,11 +20,10 @@ pub fn part_one(input: &str) -> Option<u64> {
                 iter.next().unwrap().parse::<usize>().unwrap(),
             )
         })
-        .collect::<Vec<_>>();
-
-    // Changes between example and input.
-    let amount = if boxes.len() == 20 { 10 } else { 1000 };
+        .collect()
+}
 
+pub fn part_one(boxes: &[JunctionBox], params: &Params) -> Option<u64> {
     let mut circuits: Vec<_> = (0..boxes.len()).map(|i| vec![i]).collect();
 
     {
@@ -34,7 +42,7 @@ pub fn part_one(input: &str) -> Option<u64> {
             })
             .collect::<Vec<_>>();
         distances.sort_unstable_by_key(|(_, distance)| *distance);
-        distances.into_iter().take(amount)
+        distances.into_iter().take(params.connections)
     }
     .for_each(|((a, b), _)| {
         let a_circuit = circuits
@@ -75,19 +83,7 @@ pub fn part_one(input: &str) -> Option<u64> {
         .map(|a| a as u64)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let boxes = input
-        .lines()
-        .map(|line| line.split(','))
-        .map(|mut iter| {
-            (
-                iter.next().unwrap().parse::<usize>().unwrap(),
-                iter.next().unwrap().parse::<usize>().unwrap(),
-                iter.next().unwrap().parse::<usize>().unwrap(),
-            )
-        })
-        .collect::<Vec<_>>();
-
+pub fn part_two(boxes: &[JunctionBox], _params: &Params) -> Option<u64> {
     let mut circuits: Vec<_> = (0..boxes.len()).map(|i| vec![i]).collect();
 
     let distances = {
@@ -145,19 +141,4 @@ pub fn part_two(input: &str) -> Option<u64> {
     None
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(40));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(25272));
-    }
-}
+advent_of_code::example_tests!();
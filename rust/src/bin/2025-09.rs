@@ -1,4 +1,4 @@
-advent_of_code::solution!(9);
+advent_of_code::solution!(9, year = 2025);
 
 pub fn part_one(input: &str) -> Option<u64> {
     let pairs = input
@@ -120,24 +120,4 @@ pub fn part_two(input: &str) -> Option<u64> {
     None
 }
 
-// 122383625: Low
-// 2440276020: High
-// 123274025: Low
-// 173149830
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(50));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(24));
-    }
-}
+advent_of_code::example_tests!();
@@ -1,3 +1,4 @@
+use std::time::Duration;
 use std::{
     cmp::Ordering,
     collections::VecDeque,
@@ -6,7 +7,12 @@ use std::{
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign},
 };
 
-advent_of_code::solution!(10);
+advent_of_code::solution!(
+    10,
+    year = 2025,
+    parse = parse,
+    timeout = Duration::from_secs(60)
+);
 
 fn lcm(a: usize, b: usize) -> usize {
     a * b / gcd(a, b)
@@ -482,25 +488,52 @@ fn build_usize(iter: impl Iterator<Item = bool>) -> usize {
     iter.fold(0, |value, b| (value << 1) | (b as usize) & 1)
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
+pub struct Machine {
+    /// Bitmask of the lights that have to be on.
+    lights: usize,
+    /// Indices of the lights and joltage counters each button toggles.
+    buttons: Vec<Vec<usize>>,
+    joltages: Vec<usize>,
+}
+
+pub fn parse(input: &str) -> Vec<Machine> {
+    input
+        .lines()
+        .map(|line| line.split(' '))
+        .map(|mut line| {
+            let lights = line.next().unwrap().as_bytes();
+            let joltages = line.next_back().unwrap();
+
+            Machine {
+                lights: build_usize(lights[1..lights.len() - 1].iter().rev().map(|c| *c == b'#')),
+                buttons: line
+                    .map(|collection| {
+                        collection[1..collection.len() - 1]
+                            .split(',')
+                            .map(|button| button.parse::<usize>().unwrap())
+                            .collect::<Vec<_>>()
+                    })
+                    .collect::<Vec<_>>(),
+                joltages: joltages[1..joltages.len() - 1]
+                    .split(',')
+                    .map(|joltage| joltage.parse::<usize>().unwrap())
+                    .collect::<Vec<_>>(),
+            }
+        })
+        .collect()
+}
+
+pub fn part_one(machines: &[Machine]) -> Option<u64> {
     Some(
-        input
-            .lines()
-            .map(|line| line.split_once(' ').unwrap())
-            .map(|(goal, rest)| {
+        machines
+            .iter()
+            .map(|machine| {
                 (
-                    {
-                        let goal = goal.as_bytes();
-                        build_usize(goal[1..goal.len() - 1].iter().rev().map(|c| *c == b'#'))
-                    },
-                    rest.split(' ')
-                        .take_while(|s| !s.starts_with('{'))
-                        .map(|s| {
-                            s[1..s.len() - 1]
-                                .split(',')
-                                .map(|c| c.parse::<usize>().unwrap())
-                                .fold(0usize, |value, i| value | (1 << i))
-                        })
+                    machine.lights,
+                    machine
+                        .buttons
+                        .iter()
+                        .map(|button| button.iter().fold(0usize, |value, i| value | (1 << i)))
                         .collect::<Vec<_>>(),
                 )
             })
@@ -531,35 +564,19 @@ pub fn part_one(input: &str) -> Option<u64> {
     )
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
+pub fn part_two(machines: &[Machine]) -> Option<u64> {
     Some(
-        input
-            .lines()
-            .map(|line| line.split(' '))
-            .map(|mut line| (line.next_back().unwrap(), line.skip(1)))
-            .map(|(joltages, buttons)| {
-                (
-                    joltages[1..joltages.len() - 1]
-                        .split(',')
-                        .map(|joltage| joltage.parse::<usize>().unwrap())
-                        .collect::<Vec<_>>(),
-                    buttons
-                        .map(|collection| {
-                            collection[1..collection.len() - 1]
-                                .split(',')
-                                .map(|button| button.parse::<usize>().unwrap())
-                                .collect::<Vec<_>>()
-                        })
-                        .collect::<Vec<_>>(),
-                )
-            })
-            .map(|(joltages, buttons)| {
+        machines
+            .iter()
+            .map(|machine| {
                 Matrix::new(
-                    joltages
-                        .into_iter()
+                    machine
+                        .joltages
+                        .iter()
                         .enumerate()
-                        .map(|(i, answer)| {
-                            buttons
+                        .map(|(i, &answer)| {
+                            machine
+                                .buttons
                                 .iter()
                                 .map(|button| if button.contains(&i) { 1 } else { 0 })
                                 .chain([answer])
@@ -576,22 +593,12 @@ pub fn part_two(input: &str) -> Option<u64> {
     )
 }
 
+advent_of_code::example_tests!();
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(7));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(33));
-    }
-
     #[test]
     fn matrix3() {
         let matrix = Matrix::new(vec![
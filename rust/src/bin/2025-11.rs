@@ -1,6 +1,14 @@
-advent_of_code::solution!(11);
+advent_of_code::solution!(11, year = 2025, parse = parse);
+
+pub struct Graph<'a> {
+    keys: Vec<&'a str>,
+    /// Indices of the devices each device is connected to.
+    paths: Vec<Vec<usize>>,
+    /// Indices of the devices that are connected to `out`.
+    ends: Vec<usize>,
+}
 
-pub fn part_one(input: &str) -> Option<u64> {
+pub fn parse(input: &str) -> Graph<'_> {
     let (keys, paths): (Vec<_>, Vec<_>) = input
         .lines()
         .map(|line| line.split_once(": ").unwrap())
@@ -27,6 +35,12 @@ pub fn part_one(input: &str) -> Option<u64> {
             },
         );
 
+    Graph { keys, paths, ends }
+}
+
+pub fn part_one(graph: &Graph) -> Option<u64> {
+    let Graph { keys, paths, ends } = graph;
+
     let start = keys
         .iter()
         .enumerate()
@@ -64,53 +78,11 @@ pub fn part_one(input: &str) -> Option<u64> {
         }
     }
 
-    Some(ends.into_iter().map(|i| counts[i]).sum())
+    Some(ends.iter().map(|&i| counts[i]).sum())
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let input = if input.len() == 10 {
-        "svr: aaa bbb
-aaa: fft
-fft: ccc
-bbb: tty
-tty: ccc
-ccc: ddd eee
-ddd: hub
-hub: fff
-eee: dac
-dac: fff
-fff: ggg hhh
-ggg: out
-hhh: out"
-    } else {
-        input
-    };
-
-    let (keys, paths): (Vec<_>, Vec<_>) = input
-        .lines()
-        .map(|line| line.split_once(": ").unwrap())
-        .unzip();
-
-    let (paths, ends) = paths
-        .into_iter()
-        .map(|connections| connections.split(' '))
-        .enumerate()
-        .flat_map(|(i, connections)| connections.map(move |connection| (i, connection)))
-        .fold(
-            (vec![vec![]; keys.len()], Vec::new()),
-            |(mut paths, mut ends), (i, connection)| {
-                match connection {
-                    "out" => {
-                        ends.push(i);
-                    }
-                    key => {
-                        paths[i].push(keys.iter().enumerate().find(|(_, k)| **k == key).unwrap().0);
-                    }
-                }
-
-                (paths, ends)
-            },
-        );
+pub fn part_two(graph: &Graph) -> Option<u64> {
+    let Graph { keys, paths, ends } = graph;
 
     let checkpoints: [usize; 2] = keys
         .iter()
@@ -185,23 +157,7 @@ hhh: out"
         }
     }
 
-    Some(ends.into_iter().map(|i| counts[i].2).sum())
+    Some(ends.iter().map(|&i| counts[i].2).sum())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(5));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(2));
-        panic!()
-    }
-}
+advent_of_code::example_tests!();
@@ -0,0 +1,349 @@
+//! Command-line interface, shared by the main binary and `aoc-runner`.
+use advent_of_code::template::commands::bench_diff::DEFAULT_ROUNDS;
+use advent_of_code::template::commands::time::{CompareOptions, DEFAULT_THRESHOLD};
+#[cfg(feature = "today")]
+use advent_of_code::template::commands::today;
+use advent_of_code::template::commands::{
+    all, bench_diff, download, read, report, scaffold, solve, stars, time, verify,
+};
+use advent_of_code::template::crate_dir;
+use advent_of_code::template::registry::Registry;
+use advent_of_code::template::report::Format;
+use advent_of_code::template::runner::parse_timeout;
+use advent_of_code::template::{Day, PuzzleId, Year};
+use std::env;
+use std::num::NonZeroUsize;
+use std::process::{self, Command};
+use std::time::Duration;
+
+pub enum AppArguments {
+    Download {
+        puzzle: PuzzleId,
+    },
+    Read {
+        puzzle: PuzzleId,
+    },
+    Scaffold {
+        puzzle: PuzzleId,
+        download: bool,
+        overwrite: bool,
+        template: Option<String>,
+    },
+    Solve {
+        puzzle: PuzzleId,
+        release: bool,
+        dhat: bool,
+        submit: Option<u8>,
+        timeout: Option<Duration>,
+    },
+    All {
+        year: Year,
+        release: bool,
+        isolated: bool,
+        jobs: usize,
+        timeout: Option<Duration>,
+    },
+    Time {
+        year: Year,
+        all: bool,
+        day: Option<Day>,
+        store: bool,
+        isolated: bool,
+        dhat: bool,
+        jobs: usize,
+        timeout: Option<Duration>,
+        compare: Option<CompareOptions>,
+    },
+    BenchDiff {
+        year: Year,
+        base: String,
+        head: String,
+        day: Option<Day>,
+        rounds: usize,
+        timeout: Option<Duration>,
+    },
+    Verify {
+        year: Year,
+        day: Option<Day>,
+    },
+    Stars {
+        year: Year,
+        fetch: bool,
+    },
+    Report {
+        year: Year,
+        format: Format,
+    },
+    #[cfg(feature = "today")]
+    Today,
+}
+
+/// Resolves the year from the `--year` option, falling back to `AOC_YEAR`.
+fn resolve_year(year: Option<Year>) -> Result<Year, Box<dyn std::error::Error>> {
+    year.or_else(Year::from_env)
+        .ok_or_else(|| "no year specified, pass `--year <year>` or set `AOC_YEAR`.".into())
+}
+
+/// Resolves a day of the year from the `--year` option, falling back to `AOC_YEAR`.
+fn resolve_puzzle(year: Option<Year>, day: Day) -> Result<PuzzleId, Box<dyn std::error::Error>> {
+    let year = resolve_year(year)?;
+    PuzzleId::new(year, day).ok_or_else(|| format!("day {day} does not exist in {year}.").into())
+}
+
+/// Resolves an optional day of the year from the `--year` option, see [`resolve_puzzle`].
+fn resolve_day(
+    year: Option<Year>,
+    day: Option<Day>,
+) -> Result<(Year, Option<Day>), Box<dyn std::error::Error>> {
+    match day {
+        Some(day) => resolve_puzzle(year, day).map(|puzzle| (puzzle.year, Some(puzzle.day))),
+        None => Ok((resolve_year(year)?, None)),
+    }
+}
+
+/// Parses the `--jobs` option. Days run sequentially unless it is passed.
+fn parse_jobs(args: &mut pico_args::Arguments) -> Result<usize, pico_args::Error> {
+    Ok(args
+        .opt_value_from_str::<_, NonZeroUsize>("--jobs")?
+        .map_or(1, NonZeroUsize::get))
+}
+
+pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
+    let mut args = pico_args::Arguments::from_env();
+
+    let subcommand = args.subcommand()?;
+    // NOTE: options have to be parsed before any free-standing arguments.
+    let year: Option<Year> = args.opt_value_from_str("--year")?;
+
+    let app_args = match subcommand.as_deref() {
+        Some("all") => AppArguments::All {
+            year: resolve_year(year)?,
+            release: args.contains("--release"),
+            isolated: args.contains("--isolated"),
+            jobs: parse_jobs(&mut args)?,
+            timeout: args.opt_value_from_fn("--timeout", parse_timeout)?,
+        },
+        Some("time") => {
+            let jobs = parse_jobs(&mut args)?;
+            let timeout = args.opt_value_from_fn("--timeout", parse_timeout)?;
+            let all = args.contains("--all");
+            let store = args.contains("--store");
+            let isolated = args.contains("--isolated");
+            let dhat = args.contains("--dhat");
+            // NOTE: passing a baseline or threshold implies `--compare`.
+            let baseline: Option<String> = args.opt_value_from_str("--baseline")?;
+            let threshold: Option<f64> = args.opt_value_from_str("--threshold")?;
+            let compare = (args.contains("--compare") || baseline.is_some() || threshold.is_some())
+                .then(|| CompareOptions {
+                    baseline,
+                    threshold: threshold.unwrap_or(DEFAULT_THRESHOLD),
+                });
+
+            let (year, day) = resolve_day(year, args.opt_free_from_str()?)?;
+
+            AppArguments::Time {
+                year,
+                all,
+                day,
+                store,
+                isolated,
+                dhat,
+                jobs,
+                timeout,
+                compare,
+            }
+        }
+        Some("bench-diff") => {
+            let timeout = args.opt_value_from_fn("--timeout", parse_timeout)?;
+            let (year, day) = resolve_day(year, args.opt_value_from_str("--day")?)?;
+            let rounds = args
+                .opt_value_from_str::<_, NonZeroUsize>("--rounds")?
+                .map_or(DEFAULT_ROUNDS, NonZeroUsize::get);
+
+            AppArguments::BenchDiff {
+                year,
+                base: args.free_from_str()?,
+                head: args
+                    .opt_free_from_str()?
+                    .unwrap_or_else(|| "HEAD".to_string()),
+                day,
+                rounds,
+                timeout,
+            }
+        }
+        Some("verify") => {
+            let all = args.contains("--all");
+            let (year, day) = resolve_day(year, args.opt_free_from_str()?)?;
+
+            if !all && day.is_none() {
+                return Err("specify a day to verify, or pass `--all` to verify all days.".into());
+            }
+
+            AppArguments::Verify { year, day }
+        }
+        Some("stars") => AppArguments::Stars {
+            year: resolve_year(year)?,
+            fetch: args.contains("--fetch"),
+        },
+        Some("export") => AppArguments::Report {
+            year: resolve_year(year)?,
+            format: args
+                .opt_value_from_str("--format")?
+                .unwrap_or(Format::Markdown),
+        },
+        Some("download") => AppArguments::Download {
+            puzzle: resolve_puzzle(year, args.free_from_str()?)?,
+        },
+        Some("read") => AppArguments::Read {
+            puzzle: resolve_puzzle(year, args.free_from_str()?)?,
+        },
+        Some("scaffold") => AppArguments::Scaffold {
+            puzzle: resolve_puzzle(year, args.free_from_str()?)?,
+            download: args.contains("--download"),
+            overwrite: args.contains("--overwrite"),
+            template: args.opt_value_from_str("--template")?,
+        },
+        Some("solve") => {
+            let timeout = args.opt_value_from_fn("--timeout", parse_timeout)?;
+
+            AppArguments::Solve {
+                puzzle: resolve_puzzle(year, args.free_from_str()?)?,
+                release: args.contains("--release"),
+                submit: args.opt_value_from_str("--submit")?,
+                dhat: args.contains("--dhat"),
+                timeout,
+            }
+        }
+        #[cfg(feature = "today")]
+        Some("today") => AppArguments::Today,
+        Some(x) => {
+            eprintln!("Unknown command: {x}");
+            process::exit(1);
+        }
+        None => {
+            eprintln!("No command specified.");
+            process::exit(1);
+        }
+    };
+
+    let remaining = args.finish();
+    if !remaining.is_empty() {
+        eprintln!("Warning: unknown argument(s): {remaining:?}.");
+    }
+
+    Ok(app_args)
+}
+
+/// Parses the arguments and runs the command. Commands that run solutions in-process need the
+/// registry, which only `aoc-runner` has, so the main binary forwards them to it.
+pub fn run(registry: Option<fn() -> Registry>) {
+    let registry = || registry.map_or_else(|| forward_to_runner(), |registry| registry());
+
+    match parse() {
+        Err(err) => {
+            eprintln!("Error: {err}");
+            std::process::exit(1);
+        }
+        Ok(args) => match args {
+            AppArguments::All {
+                year,
+                release,
+                isolated,
+                jobs,
+                timeout,
+            } => all::handle(&registry(), year, isolated, release, jobs, timeout),
+            AppArguments::Time {
+                year,
+                day,
+                all,
+                store,
+                isolated,
+                dhat,
+                jobs,
+                timeout,
+                compare,
+            } => time::handle(
+                &registry(),
+                year,
+                day,
+                all,
+                store,
+                isolated,
+                dhat,
+                jobs,
+                timeout,
+                compare,
+            ),
+            AppArguments::BenchDiff {
+                year,
+                base,
+                head,
+                day,
+                rounds,
+                timeout,
+            } => bench_diff::handle(year, &base, &head, day, rounds, timeout),
+            AppArguments::Verify { year, day } => verify::handle(&registry(), year, day),
+            AppArguments::Stars { year, fetch } => stars::handle(year, fetch),
+            AppArguments::Report { year, format } => report::handle(year, format),
+            AppArguments::Download { puzzle } => download::handle(puzzle),
+            AppArguments::Read { puzzle } => read::handle(puzzle),
+            AppArguments::Scaffold {
+                puzzle,
+                download,
+                overwrite,
+                template,
+            } => {
+                // NOTE: download first, so the scaffolded module can use the title of the puzzle.
+                if download {
+                    download::handle(puzzle);
+                }
+                scaffold::handle(
+                    puzzle,
+                    overwrite,
+                    template.as_deref().unwrap_or(scaffold::DEFAULT_TEMPLATE),
+                );
+            }
+            AppArguments::Solve {
+                puzzle,
+                release,
+                dhat,
+                submit,
+                timeout,
+            } => solve::handle(puzzle, release, dhat, submit, timeout),
+            #[cfg(feature = "today")]
+            AppArguments::Today => {
+                match PuzzleId::today() {
+                    Some(puzzle) => today::handle(puzzle),
+                    None => {
+                        eprintln!(
+                            "`today` command can only be run on a puzzle day of december. \
+                            Please use `scaffold` with a specific day."
+                        );
+                        process::exit(1)
+                    }
+                };
+            }
+        },
+    };
+}
+
+/// Runs the current command in the `aoc-runner` binary, which includes every solution.
+fn forward_to_runner() -> ! {
+    let mut command = Command::new("cargo");
+    command.args(["run", "--quiet", "--bin", "aoc-runner"]);
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+    command
+        .arg("--")
+        .args(env::args_os().skip(1))
+        .current_dir(crate_dir());
+
+    match command.status() {
+        Ok(status) => process::exit(status.code().unwrap_or(1)),
+        Err(e) => {
+            eprintln!("Could not run aoc-runner: {e}");
+            process::exit(1);
+        }
+    }
+}
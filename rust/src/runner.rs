@@ -0,0 +1,24 @@
+//! Runs the commands that run solutions in-process: `all`, `time` and `verify`. The build script
+//! compiles every solution into this binary, so it is kept apart from the main binary: a
+//! solution that does not compile yet only breaks these commands, not `scaffold` or `download`.
+use advent_of_code::template::registry::Registry;
+
+mod cli;
+
+/// Solutions compiled into the runner, see `build.rs`.
+#[cfg(not(any(test, feature = "dhat-heap")))]
+mod solutions {
+    include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+}
+
+fn registry() -> Registry {
+    #[cfg(not(any(test, feature = "dhat-heap")))]
+    return Registry::new(solutions::SOLUTIONS);
+
+    #[cfg(any(test, feature = "dhat-heap"))]
+    Registry::default()
+}
+
+fn main() {
+    cli::run(Some(registry));
+}
@@ -0,0 +1,256 @@
+/// Known answers of solved puzzles, used to catch regressions when refactoring solutions.
+///
+/// Answers are stored per year in `data/<year>/answers.json`, keyed by day:
+/// `{ "01": { "part_1": "1234", "part_2": "5678" } }`.
+use std::{collections::HashMap, fmt::Display, fs, io, path::PathBuf, str::FromStr};
+
+use tinyjson::JsonValue;
+
+use crate::template::{Day, PuzzleId, Year, data_path};
+
+fn get_answers_path(year: Year) -> PathBuf {
+    data_path(year, "answers.json")
+}
+
+/// Result of checking an answer against the known answers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    /// There is no known answer for the part.
+    Unknown,
+    Correct,
+    Incorrect {
+        expected: String,
+    },
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Unknown => Ok(()),
+            Verdict::Correct => f.write_str(" ✔"),
+            Verdict::Incorrect { expected } => write!(f, " ✘ (expected {expected})"),
+        }
+    }
+}
+
+/// Known answers of a single day.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DayAnswers {
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+/// Known answers of all days of a year.
+#[derive(Clone, Debug, Default)]
+pub struct Answers {
+    pub data: HashMap<Day, DayAnswers>,
+}
+
+impl Answers {
+    /// Read the known answers of a year. If the file is not present, returns empty answers.
+    pub fn read_from_file(year: Year) -> Result<Self, String> {
+        match fs::read_to_string(get_answers_path(year)) {
+            Ok(contents) => Answers::from_str(&contents),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// Write the known answers of a year to its file.
+    pub fn store_file(&self, year: Year) -> io::Result<()> {
+        let path = get_answers_path(year);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = fs::File::create(path)?;
+        JsonValue::from(self).format_to(&mut file)
+    }
+
+    /// Returns the known answer of a part, if any.
+    pub fn get(&self, day: Day, part: u8) -> Option<&str> {
+        let answers = self.data.get(&day)?;
+        match part {
+            1 => answers.part_1.as_deref(),
+            2 => answers.part_2.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Set the known answer of a part.
+    pub fn set(&mut self, day: Day, part: u8, answer: &str) {
+        let answers = self.data.entry(day).or_default();
+        match part {
+            1 => answers.part_1 = Some(answer.into()),
+            2 => answers.part_2 = Some(answer.into()),
+            _ => {}
+        }
+    }
+
+    /// Check an answer against the known answer of a part.
+    pub fn check(&self, day: Day, part: u8, answer: Option<&str>) -> Verdict {
+        match self.get(day, part) {
+            None => Verdict::Unknown,
+            Some(expected) if answer == Some(expected) => Verdict::Correct,
+            Some(expected) => Verdict::Incorrect {
+                expected: expected.into(),
+            },
+        }
+    }
+}
+
+/// Check the answer of a part against the known answers of its year.
+/// Problems with the answers file are printed and treated as unknown answers.
+pub fn check_answer(puzzle: PuzzleId, part: u8, answer: Option<&str>) -> Verdict {
+    match Answers::read_from_file(puzzle.year) {
+        Ok(answers) => answers.check(puzzle.day, part, answer),
+        Err(e) => {
+            eprintln!("Could not read known answers: {e}");
+            Verdict::Unknown
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Answers> for JsonValue {
+    fn from(value: &Answers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        for (day, answers) in &value.data {
+            let mut day_map: HashMap<String, JsonValue> = HashMap::new();
+            for (key, answer) in [("part_1", &answers.part_1), ("part_2", &answers.part_2)] {
+                if let Some(answer) = answer {
+                    day_map.insert(key.into(), JsonValue::String(answer.clone()));
+                }
+            }
+            map.insert(day.to_string(), JsonValue::Object(day_map));
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+/// Largest integer up to which every integer is exactly representable as `f64`, i.e. `2^53 - 1`.
+const MAX_EXACT_INTEGER: f64 = 9_007_199_254_740_991.0;
+
+impl FromStr for Answers {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let json = JsonValue::from_str(s).or(Err("not valid JSON file."))?;
+
+        let days = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let mut data = HashMap::new();
+
+        for (day, answers) in days {
+            let day = Day::from_str(day).or(Err(format!("`{day}` is not a valid day.")))?;
+
+            let answers = answers
+                .get::<HashMap<String, JsonValue>>()
+                .ok_or(format!("expected answers of day {day} to be an object."))?;
+
+            // NOTE: JSON numbers are parsed as `f64`, which can not represent every integer
+            // above 2^53. Such answers, and answers that are not integers, must be strings.
+            #[allow(clippy::cast_possible_truncation)]
+            let part = |key: &str| match answers.get(key) {
+                None | Some(JsonValue::Null) => Ok(None),
+                Some(JsonValue::String(answer)) => Ok(Some(answer.clone())),
+                Some(JsonValue::Number(answer))
+                    if answer.fract() == 0.0 && answer.abs() <= MAX_EXACT_INTEGER =>
+                {
+                    Ok(Some((*answer as i64).to_string()))
+                }
+                Some(JsonValue::Number(answer)) => Err(format!(
+                    "{key} of day {day} can not be read exactly as a number, write it as a string instead of {answer}."
+                )),
+                Some(_) => Err(format!(
+                    "expected {key} of day {day} to be null or a string."
+                )),
+            };
+
+            data.insert(
+                day,
+                DayAnswers {
+                    part_1: part("part_1")?,
+                    part_2: part("part_2")?,
+                },
+            );
+        }
+
+        Ok(Answers { data })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use std::str::FromStr;
+
+    use tinyjson::JsonValue;
+
+    use super::{Answers, Verdict};
+    use crate::day;
+
+    #[test]
+    fn parses_answers() {
+        let answers = Answers::from_str(
+            r#"{ "01": { "part_1": "1234", "part_2": null }, "02": { "part_2": 42 } }"#,
+        )
+        .unwrap();
+        assert_eq!(answers.get(day!(1), 1), Some("1234"));
+        assert_eq!(answers.get(day!(1), 2), None);
+        assert_eq!(answers.get(day!(2), 1), None);
+        assert_eq!(answers.get(day!(2), 2), Some("42"));
+        assert_eq!(answers.get(day!(3), 1), None);
+    }
+
+    #[test]
+    fn rejects_malformed_answers() {
+        assert!(Answers::from_str(r#"{ "26": { "part_1": "1" } }"#).is_err());
+        assert!(Answers::from_str(r#"{ "01": { "part_1": [] } }"#).is_err());
+        assert!(Answers::from_str(r#"[]"#).is_err());
+    }
+
+    #[test]
+    fn rejects_inexact_numbers() {
+        let answers = Answers::from_str(r#"{ "01": { "part_1": 9007199254740991 } }"#).unwrap();
+        assert_eq!(answers.get(day!(1), 1), Some("9007199254740991"));
+
+        assert!(Answers::from_str(r#"{ "01": { "part_1": 9007199254740993 } }"#).is_err());
+        assert!(Answers::from_str(r#"{ "01": { "part_1": 1.5 } }"#).is_err());
+    }
+
+    #[test]
+    fn checks_answers() {
+        let answers = Answers::from_str(r#"{ "01": { "part_1": "1234" } }"#).unwrap();
+        assert_eq!(answers.check(day!(1), 1, Some("1234")), Verdict::Correct);
+        assert_eq!(
+            answers.check(day!(1), 1, Some("4321")),
+            Verdict::Incorrect {
+                expected: "1234".into()
+            }
+        );
+        assert_eq!(
+            answers.check(day!(1), 1, None),
+            Verdict::Incorrect {
+                expected: "1234".into()
+            }
+        );
+        assert_eq!(answers.check(day!(1), 2, Some("1")), Verdict::Unknown);
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        let mut answers = Answers::default();
+        answers.set(day!(1), 1, "1234");
+        answers.set(day!(2), 2, "abc");
+
+        let json = JsonValue::from(&answers).stringify().unwrap();
+        let parsed = Answers::from_str(&json).unwrap();
+        assert_eq!(parsed.data, answers.data);
+    }
+}
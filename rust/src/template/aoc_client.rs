@@ -0,0 +1,334 @@
+/// Built-in client for the Advent of Code website.
+///
+/// Requests are authenticated with the session cookie of the website, which is read from the
+/// `ADVENT_OF_CODE_SESSION` environment variable or from a `.adventofcode.session` file.
+/// The base URL can be changed via `AOC_BASE_URL`, e.g. to test against a local server.
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::template::{PuzzleId, Year, data_path, html};
+
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+pub const SESSION_ENV: &str = "ADVENT_OF_CODE_SESSION";
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const SESSION_FILE: &str = "adventofcode.session";
+const USER_AGENT: &str = "github.com/fspoettel/advent-of-code-rust";
+
+// NOTE: clients can only be redirected by tests.
+#[cfg(feature = "test_lib")]
+thread_local! {
+    /// Base URL and session of clients created on the current thread, if redirected via
+    /// [`redirect_client`].
+    static REDIRECT: std::cell::RefCell<Option<(String, String)>> =
+        const { std::cell::RefCell::new(None) };
+}
+
+/// Redirects the clients created on the current thread to a base URL and session, e.g. to a local
+/// server in tests. Other threads, including threads spawned by the current one, are not affected.
+#[cfg(feature = "test_lib")]
+pub fn redirect_client(target: Option<(&str, &str)>) {
+    REDIRECT.set(target.map(|(base_url, session)| (base_url.into(), session.into())));
+}
+
+#[derive(Debug)]
+pub enum AocClientError {
+    SessionNotFound,
+    Request(String),
+    BadStatus { status: u16, body: String },
+    IO(io::Error),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::SessionNotFound => write!(
+                f,
+                "no session cookie found, set `{SESSION_ENV}` or create `~/.{SESSION_FILE}`."
+            ),
+            AocClientError::Request(e) => write!(f, "request failed: {e}"),
+            AocClientError::BadStatus { status, body } => {
+                write!(f, "server responded with status {status}: {}", body.trim())
+            }
+            AocClientError::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::IO(e)
+    }
+}
+
+impl From<ureq::Error> for AocClientError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(status, response) => AocClientError::BadStatus {
+                status,
+                body: response.into_string().unwrap_or_default(),
+            },
+            ureq::Error::Transport(e) => AocClientError::Request(e.to_string()),
+        }
+    }
+}
+
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    /// Creates a client for the base URL in `AOC_BASE_URL`, authenticated with the stored session,
+    /// unless the current thread was redirected via `redirect_client` in tests.
+    pub fn from_env() -> Result<Self, AocClientError> {
+        #[cfg(feature = "test_lib")]
+        if let Some((base_url, session)) = REDIRECT.with_borrow(Clone::clone) {
+            return Ok(Self::new(&base_url, &session));
+        }
+
+        let base_url = env::var(BASE_URL_ENV).unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        Ok(Self::new(&base_url, &load_session()?))
+    }
+
+    /// Fetches the personal puzzle input.
+    pub fn get_input(&self, puzzle: PuzzleId) -> Result<String, AocClientError> {
+        self.get(&format!("{}/input", self.puzzle_url(puzzle)))
+    }
+
+    /// Fetches the description of a puzzle as HTML. Once part one is solved,
+    /// the description contains both parts.
+    pub fn get_puzzle(&self, puzzle: PuzzleId) -> Result<String, AocClientError> {
+        let page = self.get(&self.puzzle_url(puzzle))?;
+        Ok(html::extract_articles(&page))
+    }
+
+    /// Fetches the calendar page of a year, which shows the stars collected on every day.
+    pub fn get_calendar(&self, year: Year) -> Result<String, AocClientError> {
+        self.get(&format!("{}/{year}", self.base_url))
+    }
+
+    /// Submits an answer and returns the response message as text.
+    pub fn submit(
+        &self,
+        puzzle: PuzzleId,
+        part: u8,
+        answer: &str,
+    ) -> Result<String, AocClientError> {
+        let page = self
+            .agent
+            .post(&format!("{}/answer", self.puzzle_url(puzzle)))
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?
+            .into_string()?;
+
+        Ok(html::to_text(&html::extract_articles(&page)))
+    }
+
+    fn puzzle_url(&self, puzzle: PuzzleId) -> String {
+        format!(
+            "{}/{}/day/{}",
+            self.base_url,
+            puzzle.year,
+            puzzle.day.into_inner()
+        )
+    }
+
+    fn get(&self, url: &str) -> Result<String, AocClientError> {
+        Ok(self
+            .agent
+            .get(url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()?
+            .into_string()?)
+    }
+}
+
+/// Loads the session cookie from `ADVENT_OF_CODE_SESSION`, `~/.adventofcode.session`
+/// or `<config_dir>/adventofcode.session`, in that order.
+fn load_session() -> Result<String, AocClientError> {
+    if let Ok(session) = env::var(SESSION_ENV)
+        && !session.trim().is_empty()
+    {
+        return Ok(session.trim().to_string());
+    }
+
+    let home = env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(PathBuf::from);
+
+    let config_dir = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| home.as_ref().map(|home| home.join(".config")));
+
+    home.map(|home| home.join(format!(".{SESSION_FILE}")))
+        .into_iter()
+        .chain(config_dir.map(|dir| dir.join(SESSION_FILE)))
+        .find_map(|path| fs::read_to_string(path).ok())
+        .map(|session| session.trim().to_string())
+        .filter(|session| !session.is_empty())
+        .ok_or(AocClientError::SessionNotFound)
+}
+
+pub fn read(puzzle: PuzzleId) -> Result<(), AocClientError> {
+    let client = AocClient::from_env()?;
+    let description = client.get_puzzle(puzzle)?;
+    write_file(&get_puzzle_path(puzzle), &description)?;
+    println!("{}", html::to_text(&description));
+    Ok(())
+}
+
+pub fn download(puzzle: PuzzleId) -> Result<(), AocClientError> {
+    let client = AocClient::from_env()?;
+    let input_path = get_input_path(puzzle);
+    let puzzle_path = get_puzzle_path(puzzle);
+
+    let input = client.get_input(puzzle)?;
+    write_file(&input_path, &input)?;
+
+    let description = client.get_puzzle(puzzle)?;
+    write_file(&puzzle_path, &description)?;
+
+    println!("---");
+    println!(
+        "🎄 Successfully wrote input to \"{}\".",
+        input_path.display()
+    );
+    println!(
+        "🎄 Successfully wrote puzzle to \"{}\".",
+        puzzle_path.display()
+    );
+    Ok(())
+}
+
+/// Submits an answer and prints the response message, which is also returned.
+pub fn submit(puzzle: PuzzleId, part: u8, result: &str) -> Result<String, AocClientError> {
+    let client = AocClient::from_env()?;
+    let response = client.submit(puzzle, part, result)?;
+    println!("{response}");
+    Ok(response)
+}
+
+fn write_file(path: &Path, contents: &str) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, contents)
+}
+
+fn get_input_path(puzzle: PuzzleId) -> PathBuf {
+    data_path(puzzle.year, format!("inputs/{}.txt", puzzle.day))
+}
+
+/// Returns the path of the downloaded description of a puzzle, which holds the HTML of its
+/// `<article>` elements.
+pub fn get_puzzle_path(puzzle: PuzzleId) -> PathBuf {
+    data_path(puzzle.year, format!("puzzles/{}.html", puzzle.day))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{AocClient, AocClientError};
+    use crate::template::PuzzleId;
+    use crate::template::guesses::SubmitVerdict;
+    use crate::template::mock_server::{MockServer, SESSION, Sandbox, Submission};
+    use crate::{day, year};
+
+    const ARTICLE: &str = "<article class=\"day-desc\"><h2>--- Day 1: Test ---</h2><p>Count the <em>lines</em>.</p></article>";
+
+    fn puzzle() -> PuzzleId {
+        PuzzleId::new(year!(2025), day!(1)).unwrap()
+    }
+
+    fn server() -> MockServer {
+        MockServer::builder()
+            .puzzle(puzzle(), ARTICLE)
+            .input(puzzle(), "1\n2\n3\n")
+            .answer(puzzle(), 1, "3")
+            .start()
+    }
+
+    #[test]
+    fn fetches_input_and_puzzle() {
+        let server = server();
+        let client = AocClient::new(&server.base_url(), SESSION);
+        assert_eq!(client.get_input(puzzle()).unwrap(), "1\n2\n3\n");
+        assert_eq!(client.get_puzzle(puzzle()).unwrap(), ARTICLE);
+    }
+
+    #[test]
+    fn rejects_invalid_session() {
+        let server = server();
+        let client = AocClient::new(&server.base_url(), "invalid");
+        assert!(matches!(
+            client.get_input(puzzle()),
+            Err(AocClientError::BadStatus { status: 400, .. })
+        ));
+    }
+
+    #[test]
+    fn submits_answers() {
+        let server = server();
+        let client = AocClient::new(&server.base_url(), SESSION);
+
+        let verdict = |answer: &str| {
+            let response = client.submit(puzzle(), 1, answer).unwrap();
+            SubmitVerdict::from_response(&response)
+        };
+
+        assert_eq!(verdict("4"), Some(SubmitVerdict::TooHigh));
+        assert_eq!(verdict("2"), Some(SubmitVerdict::TooLow));
+        assert_eq!(verdict("abc"), Some(SubmitVerdict::Wrong));
+        assert_eq!(verdict("3"), Some(SubmitVerdict::Correct));
+        assert_eq!(
+            server.submissions().last(),
+            Some(&Submission {
+                puzzle: puzzle(),
+                part: 1,
+                answer: "3".into()
+            })
+        );
+    }
+
+    #[test]
+    fn does_not_judge_rate_limited_submissions() {
+        let server = MockServer::builder().rate_limited().start();
+        let client = AocClient::new(&server.base_url(), SESSION);
+        let response = client.submit(puzzle(), 1, "3").unwrap();
+        assert!(response.contains("You gave an answer too recently"));
+        assert_eq!(SubmitVerdict::from_response(&response), None);
+        assert!(server.submissions().is_empty());
+    }
+
+    #[test]
+    fn downloads_input_and_puzzle() {
+        let server = server();
+        let sandbox = Sandbox::new(&server);
+        super::download(puzzle()).unwrap();
+        assert_eq!(sandbox.read("data/2025/inputs/01.txt"), "1\n2\n3\n");
+        assert_eq!(sandbox.read("data/2025/puzzles/01.html"), ARTICLE);
+    }
+
+    #[test]
+    fn reads_puzzle() {
+        let server = server();
+        let sandbox = Sandbox::new(&server);
+        super::read(puzzle()).unwrap();
+        assert_eq!(sandbox.read("data/2025/puzzles/01.html"), ARTICLE);
+    }
+}
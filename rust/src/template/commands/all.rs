@@ -1,5 +1,31 @@
-use crate::template::{all_days, run_multi::run_multi};
+use std::time::Duration;
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+use crate::template::registry::Registry;
+use crate::template::run_multi::{Executor, run_multi};
+use crate::template::runner::RunOptions;
+use crate::template::{Year, all_days};
+
+pub fn handle(
+    registry: &Registry,
+    year: Year,
+    isolated: bool,
+    is_release: bool,
+    jobs: usize,
+    timeout: Option<Duration>,
+) {
+    let executor = if isolated {
+        Executor::Isolated {
+            is_release,
+            dhat: false,
+        }
+    } else {
+        Executor::InProcess(registry)
+    };
+
+    let options = RunOptions {
+        timed: false,
+        timeout,
+    };
+
+    run_multi(year, &all_days(year).collect(), &executor, options, jobs);
 }
@@ -0,0 +1,365 @@
+use std::path::{Path, PathBuf};
+use std::process::{self, Command};
+use std::time::Duration;
+use std::{env, fs};
+
+use crate::template::output;
+use crate::template::part_report::PARSE_PART;
+use crate::template::run_multi::child_commands::{run_reporting, solution_args};
+use crate::template::run_multi::timing_from_reports;
+use crate::template::runner::RunOptions;
+use crate::template::stats::BenchStats;
+use crate::template::timings::{Change, Metadata, PartTiming, Timing, Timings};
+use crate::template::{
+    ANSI_BOLD, ANSI_RESET, DATA_DIR_ENV, Day, PuzzleId, Year, all_days, data_dir, git,
+};
+
+/// Number of interleaved rounds, unless `--rounds` is passed.
+pub const DEFAULT_ROUNDS: usize = 5;
+
+/// A revision checked out into a worktree of its own. The worktree is removed when dropped, the
+/// build is kept in `target/bench-diff` to speed up later comparisons of the revision.
+struct Checkout {
+    revision: String,
+    worktree: PathBuf,
+    crate_dir: PathBuf,
+    target_dir: PathBuf,
+}
+
+impl Checkout {
+    fn create(revision: &str, prefix: &str) -> Result<Self, String> {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("target/bench-diff");
+        let worktree = root.join("worktrees").join(revision);
+
+        // NOTE: a previous comparison may have been aborted before it cleaned up.
+        if worktree.exists() {
+            let _ = git::remove_worktree(&worktree);
+            let _ = fs::remove_dir_all(&worktree);
+        }
+        git::add_worktree(&worktree, revision)?;
+
+        Ok(Self {
+            revision: revision.to_string(),
+            crate_dir: worktree.join(prefix),
+            target_dir: root.join("targets").join(revision),
+            worktree,
+        })
+    }
+
+    fn build(&self) -> Result<(), String> {
+        println!("Building {}...", self.revision);
+
+        // NOTE: use the same dependency versions on both sides.
+        let lockfile = Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.lock");
+        let worktree_lockfile = self.crate_dir.join("Cargo.lock");
+        if lockfile.exists() && !worktree_lockfile.exists() {
+            fs::copy(lockfile, worktree_lockfile).map_err(|e| e.to_string())?;
+        }
+
+        let status = Command::new("cargo")
+            .args(["build", "--release", "--quiet", "--bins"])
+            .current_dir(&self.crate_dir)
+            .env("CARGO_TARGET_DIR", &self.target_dir)
+            .status()
+            .map_err(|e| format!("could not run cargo: {e}"))?;
+
+        if status.success() {
+            Ok(())
+        } else {
+            Err(format!("could not build {}.", self.revision))
+        }
+    }
+
+    fn has_solution(&self, puzzle: PuzzleId) -> bool {
+        self.crate_dir.join(format!("src/bin/{puzzle}.rs")).exists()
+    }
+
+    /// Bench the solution of a day, returns `None` if it did not solve any part.
+    fn bench(&self, puzzle: PuzzleId, options: RunOptions) -> Option<Timing> {
+        let bin_name = puzzle.to_string();
+        let bin_path = self
+            .target_dir
+            .join("release")
+            .join(format!("{bin_name}{}", env::consts::EXE_SUFFIX));
+
+        // NOTE: both revisions solve the inputs of the current data directory.
+        let mut command = Command::new(bin_path);
+        command
+            .args(solution_args(options))
+            .env(DATA_DIR_ENV, data_dir());
+
+        // NOTE: only the comparison is printed, not the output of the solutions.
+        let (reports, _) = output::capture(|| run_reporting(command, &bin_name));
+        let reports = reports.unwrap_or_default();
+        (!reports.is_empty()).then(|| timing_from_reports(puzzle.day, &reports))
+    }
+}
+
+impl Drop for Checkout {
+    fn drop(&mut self) {
+        if let Err(e) = git::remove_worktree(&self.worktree) {
+            eprintln!("Could not remove worktree {}: {e}", self.worktree.display());
+        }
+    }
+}
+
+/// Bench the solutions of two revisions and print how the timings of `head` compare to `base`.
+pub fn handle(
+    year: Year,
+    base: &str,
+    head: &str,
+    day: Option<Day>,
+    rounds: usize,
+    timeout: Option<Duration>,
+) {
+    if let Err(e) = compare_revisions(year, base, head, day, rounds, timeout) {
+        eprintln!("failed to compare revisions: {e}");
+        process::exit(1);
+    }
+}
+
+fn compare_revisions(
+    year: Year,
+    base: &str,
+    head: &str,
+    day: Option<Day>,
+    rounds: usize,
+    timeout: Option<Duration>,
+) -> Result<(), String> {
+    let resolve =
+        |name: &str| git::resolve_revision(name).ok_or(format!("unknown revision `{name}`."));
+    let (base, head) = (resolve(base)?, resolve(head)?);
+    if base == head {
+        return Err(format!("both revisions are {base}."));
+    }
+
+    let prefix = git::crate_prefix()?;
+    let checkouts = [
+        Checkout::create(&base, &prefix)?,
+        Checkout::create(&head, &prefix)?,
+    ];
+    for checkout in &checkouts {
+        checkout.build()?;
+    }
+
+    let puzzles: Vec<PuzzleId> = all_days(year)
+        .filter(|d| day.is_none_or(|day| day == *d))
+        .filter_map(|day| PuzzleId::new(year, day))
+        .filter(|puzzle| {
+            checkouts
+                .iter()
+                .all(|checkout| checkout.has_solution(*puzzle))
+        })
+        .collect();
+
+    if puzzles.is_empty() {
+        return Err("no solution exists at both revisions.".into());
+    }
+
+    let options = RunOptions {
+        timed: true,
+        timeout,
+    };
+    let mut runs: [Vec<Timing>; 2] = Default::default();
+
+    for round in 0..rounds {
+        println!("Round {}/{rounds}...", round + 1);
+
+        for puzzle in &puzzles {
+            // NOTE: alternate which revision runs first, so neither is favoured by warm caches.
+            let order = if round % 2 == 0 { [0, 1] } else { [1, 0] };
+            for side in order {
+                runs[side].extend(checkouts[side].bench(*puzzle, options));
+            }
+        }
+    }
+
+    let [base_runs, head_runs] = runs;
+    let base_timings = combine_rounds(&puzzles, &base_runs, &base);
+    let head_timings = combine_rounds(&puzzles, &head_runs, &head);
+
+    println!();
+    print_comparison(&base_timings, &head_timings, rounds);
+    Ok(())
+}
+
+/// Combine the runs of every round into one timing per day. The duration of a part is the median
+/// of its rounds, its statistics are over the durations of the rounds.
+fn combine_rounds(puzzles: &[PuzzleId], runs: &[Timing], revision: &str) -> Timings {
+    let mut data = vec![];
+
+    for puzzle in puzzles {
+        let rounds: Vec<&Timing> = runs.iter().filter(|run| run.day == puzzle.day).collect();
+        if rounds.is_empty() {
+            continue;
+        }
+
+        let part_timing = |part: u8| {
+            let durations: Vec<Duration> = rounds
+                .iter()
+                .filter_map(|timing| timing.duration(part))
+                .collect();
+            BenchStats::from_samples(&durations).map(|stats| PartTiming {
+                duration: stats.median,
+                samples: durations.len() as u128,
+                stats: Some(stats),
+                peak_bytes: None,
+            })
+        };
+
+        let (parse, part_1, part_2) = (part_timing(PARSE_PART), part_timing(1), part_timing(2));
+
+        data.push(Timing {
+            day: puzzle.day,
+            parse,
+            part_1,
+            part_2,
+            #[allow(clippy::cast_precision_loss)]
+            total_nanos: [parse, part_1, part_2]
+                .iter()
+                .flatten()
+                .map(|part| part.duration.as_nanos() as f64)
+                .sum(),
+            metadata: Metadata {
+                revision: Some(revision.to_string()),
+                profile: Some("release".into()),
+                ..Metadata::default()
+            },
+        });
+    }
+
+    Timings {
+        data,
+        history: vec![],
+    }
+}
+
+/// Returns whether the rounds of two revisions do not overlap, i.e. every round of one revision
+/// was faster than every round of the other.
+fn is_significant(base: &BenchStats, head: &BenchStats) -> bool {
+    let rounds = |stats: &BenchStats| stats.samples + stats.outliers;
+    rounds(base) > 1 && rounds(head) > 1 && (head.max < base.min || head.min > base.max)
+}
+
+fn print_comparison(base: &Timings, head: &Timings, rounds: usize) {
+    let revision = |timings: &Timings| {
+        timings
+            .data
+            .first()
+            .and_then(|timing| timing.metadata.revision.clone())
+            .unwrap_or_default()
+    };
+    let (base_revision, head_revision) = (revision(base), revision(head));
+
+    println!(
+        "{ANSI_BOLD}Comparing {head_revision} to {base_revision}{ANSI_RESET} (median of {rounds} rounds)"
+    );
+    println!();
+    println!("| Day | Part | {base_revision} | {head_revision} | Change |");
+    println!("| :---: | :---: | ---: | ---: | ---: |");
+
+    for change in base.compare(head, None, None) {
+        let stats = |timings: &Timings| {
+            timings
+                .data
+                .iter()
+                .find(|timing| timing.day == change.day)
+                .and_then(|timing| timing.part(change.part))
+                .and_then(|part| part.stats)
+        };
+
+        let marker = match (stats(base), stats(head)) {
+            (Some(base), Some(head)) if is_significant(&base, &head) => {
+                if change.current < change.baseline {
+                    " ✔"
+                } else {
+                    " ✘"
+                }
+            }
+            _ => "",
+        };
+
+        println!(
+            "| {} | {} | {:.1?} | {:.1?} | {:+.1}%{marker} |",
+            change.day,
+            part_name(&change),
+            change.baseline,
+            change.current,
+            change.percent()
+        );
+    }
+
+    println!();
+    println!(
+        "✔ / ✘: every round of {head_revision} was faster / slower than every round of {base_revision}."
+    );
+}
+
+fn part_name(change: &Change) -> String {
+    match change.part {
+        PARSE_PART => "Parse".into(),
+        part => part.to_string(),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use std::time::Duration;
+
+    use super::{combine_rounds, is_significant};
+    use crate::template::PuzzleId;
+    use crate::template::stats::BenchStats;
+    use crate::template::timings::{Metadata, PartTiming, Timing};
+    use crate::{day, year};
+
+    fn run(millis: u64) -> Timing {
+        Timing {
+            day: day!(1),
+            parse: None,
+            part_1: Some(PartTiming::once(Duration::from_millis(millis))),
+            part_2: None,
+            total_nanos: 0_f64,
+            metadata: Metadata::default(),
+        }
+    }
+
+    fn rounds(millis: &[u64]) -> BenchStats {
+        let durations: Vec<Duration> = millis.iter().map(|ms| Duration::from_millis(*ms)).collect();
+        BenchStats::from_samples(&durations).unwrap()
+    }
+
+    #[test]
+    fn combines_rounds() {
+        let puzzles = [PuzzleId::new(year!(2025), day!(1)).unwrap()];
+        let runs = [run(12), run(10), run(11)];
+
+        let timings = combine_rounds(&puzzles, &runs, "aaaaaaa");
+        assert_eq!(timings.data.len(), 1);
+
+        let timing = &timings.data[0];
+        let part_1 = timing.part_1.unwrap();
+        assert_eq!(part_1.duration, Duration::from_millis(11));
+        assert_eq!(part_1.samples, 3);
+        assert_eq!(part_1.stats.unwrap().max, Duration::from_millis(12));
+        assert_eq!(timing.part_2, None);
+        assert_eq!(timing.metadata.revision, Some("aaaaaaa".into()));
+        assert_eq!(timing.total_nanos, 11_000_000_f64);
+    }
+
+    #[test]
+    fn detects_significant_changes() {
+        assert!(is_significant(
+            &rounds(&[10, 11, 12]),
+            &rounds(&[13, 14, 14])
+        ));
+        assert!(is_significant(&rounds(&[10, 11, 12]), &rounds(&[7, 8, 9])));
+        assert!(!is_significant(
+            &rounds(&[10, 11, 12]),
+            &rounds(&[12, 13, 14])
+        ));
+        // NOTE: a single round tells nothing about the noise.
+        assert!(!is_significant(&rounds(&[10]), &rounds(&[20])));
+    }
+}
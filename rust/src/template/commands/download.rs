@@ -1,16 +1,26 @@
-use crate::template::{Day, aoc_cli};
+use crate::template::{PuzzleId, aoc_client, examples};
 use std::process;
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
-        );
-        process::exit(1);
-    }
-
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+pub fn handle(puzzle: PuzzleId) {
+    if let Err(e) = aoc_client::download(puzzle) {
+        eprintln!("failed to download puzzle: {e}");
         process::exit(1);
     };
+    extract_examples(puzzle);
+}
+
+/// Extract the examples of a downloaded puzzle description. Failing to do so is not fatal, as the
+/// examples can always be added by hand.
+pub(super) fn extract_examples(puzzle: PuzzleId) {
+    match examples::extract_from_puzzle(puzzle) {
+        Ok(paths) => {
+            for path in paths {
+                println!(
+                    "🎄 Extracted example to \"{}\", check the guessed answers in its front matter.",
+                    path.display()
+                );
+            }
+        }
+        Err(e) => eprintln!("failed to extract examples: {e}"),
+    }
 }
@@ -1,6 +1,11 @@
 pub mod all;
+pub mod bench_diff;
 pub mod download;
 pub mod read;
+pub mod report;
 pub mod scaffold;
 pub mod solve;
+pub mod stars;
 pub mod time;
+pub mod today;
+pub mod verify;
@@ -0,0 +1,26 @@
+use std::process;
+
+use crate::template::Year;
+use crate::template::answers::Answers;
+use crate::template::report::{Format, Report};
+use crate::template::stars::Stars;
+use crate::template::timings::Timings;
+
+/// Print a report of the timings, answers and stars of a year.
+pub fn handle(year: Year, format: Format) {
+    let (answers, stars) = match (Answers::read_from_file(year), Stars::read_from_file(year)) {
+        (Ok(answers), Ok(stars)) => (answers, stars),
+        (Err(e), _) | (_, Err(e)) => {
+            eprintln!("Could not read report data: {e}");
+            process::exit(1);
+        }
+    };
+
+    let timings = Timings::read_from_file(year).unwrap_or_else(|e| {
+        eprintln!("Ignoring invalid timings: {e}");
+        Timings::default()
+    });
+
+    let report = Report::new(year, &timings, &answers, &stars);
+    print!("{}", report.render(format));
+}
@@ -1,79 +1,386 @@
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    ffi::OsString,
+    fs, io,
+    path::{Path, PathBuf},
     process,
 };
 
-use crate::template::Day;
+use crate::template::{PuzzleId, aoc_client, crate_dir, data_path, html};
 
-const MODULE_TEMPLATE: &str =
-    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+/// The template used unless another one is passed via `--template`.
+pub const DEFAULT_TEMPLATE: &str = "default";
 
-fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
-    let mut file = OpenOptions::new();
-    if overwrite {
-        file.create(true);
-    } else {
-        file.create_new(true);
-    }
-    file.truncate(true).write(true).open(path)
+fn get_templates_dir() -> PathBuf {
+    crate_dir().join("templates")
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new()
-        .write(true)
-        .create(true)
-        .truncate(true)
-        .open(path)
+/// Where a file of a template is scaffolded to.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Target {
+    /// `solution.rs`, scaffolded to `src/bin/<year>-<day>.rs`.
+    Module,
+    /// `input.txt`, scaffolded to `data/<year>/inputs/<day>.txt`.
+    Input,
+    /// `example.txt` or `example-<name>.txt`, scaffolded to `data/<year>/examples/<day>.txt`
+    /// or `data/<year>/examples/<day>-<name>.txt`.
+    Example(Option<String>),
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("../data/inputs/{day}.txt");
-    let example_path = format!("../data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
-
-    let mut file = match safe_create_file(&module_path, overwrite) {
-        Ok(file) => file,
-        Err(e) => {
-            eprintln!("Failed to create module file: {e}");
-            process::exit(1);
+impl Target {
+    fn from_file_name(name: &str) -> Option<Self> {
+        match name {
+            "solution.rs" => Some(Target::Module),
+            "input.txt" => Some(Target::Input),
+            "example.txt" => Some(Target::Example(None)),
+            _ => name
+                .strip_prefix("example-")
+                .and_then(|name| name.strip_suffix(".txt"))
+                .filter(|name| !name.is_empty())
+                .map(|name| Target::Example(Some(name.to_string()))),
         }
-    };
+    }
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
-        Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to write module contents: {e}");
-            process::exit(1);
+    fn kind(&self) -> &'static str {
+        match self {
+            Target::Module => "module file",
+            Target::Input => "input file",
+            Target::Example(_) => "example file",
         }
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create input file: {e}");
-            process::exit(1);
+    fn path(&self, puzzle: PuzzleId) -> PathBuf {
+        let PuzzleId { year, day } = puzzle;
+        match self {
+            Target::Module => crate_dir().join(format!("src/bin/{puzzle}.rs")),
+            Target::Input => data_path(year, format!("inputs/{day}.txt")),
+            Target::Example(None) => data_path(year, format!("examples/{day}.txt")),
+            Target::Example(Some(name)) => data_path(year, format!("examples/{day}-{name}.txt")),
         }
     }
+}
+
+/// Returns the names of all templates in `templates/`.
+fn template_names() -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(get_templates_dir())
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .filter(|entry| entry.path().is_dir())
+                .map(|entry| entry.file_name().to_string_lossy().to_string())
+                .collect()
+        })
+        .unwrap_or_default();
+    names.sort_unstable();
+    names
+}
+
+/// Read the files of a template, which is a directory in `templates/`. Every template has a
+/// `solution.rs`, inputs and examples it does not provide are scaffolded empty.
+fn read_template(name: &str) -> Result<Vec<(Target, String)>, String> {
+    let dir = get_templates_dir().join(name);
+    let entries = fs::read_dir(&dir).map_err(|_| {
+        format!(
+            "Unknown template `{name}`, available templates: {}.",
+            template_names().join(", ")
+        )
+    })?;
+
+    let mut files = vec![];
+
+    for entry in entries {
+        let entry = entry.map_err(|e| e.to_string())?;
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        let Some(target) = Target::from_file_name(&file_name) else {
+            eprintln!("Skipped unknown file \"{file_name}\" of template `{name}`.");
+            continue;
+        };
+        let contents = fs::read_to_string(entry.path())
+            .map_err(|e| format!("Failed to read template file \"{file_name}\": {e}"))?;
+        files.push((target, contents));
+    }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+    if !files.iter().any(|(target, _)| *target == Target::Module) {
+        return Err(format!("Template `{name}` has no solution.rs."));
+    }
+
+    for target in [Target::Input, Target::Example(None)] {
+        if !files.iter().any(|(file, _)| *file == target) {
+            files.push((target, String::new()));
         }
+    }
+
+    files.sort_unstable();
+    Ok(files)
+}
+
+/// Returns the title of a puzzle if its description was downloaded, e.g. `Day 1: Secret Entrance`.
+fn get_title(puzzle: PuzzleId) -> String {
+    fs::read_to_string(aoc_client::get_puzzle_path(puzzle))
+        .ok()
+        .and_then(|description| html::extract_title(&description))
+        .unwrap_or_else(|| format!("Day {}", puzzle.day.into_inner()))
+}
+
+/// Replace the placeholders of a template file: `%DAY%` (e.g. `01`), `%DAY_NUMBER%` (e.g. `1`),
+/// `%YEAR%` and `%TITLE%`.
+fn render(contents: &str, puzzle: PuzzleId, title: &str) -> String {
+    contents
+        .replace("%DAY_NUMBER%", &puzzle.day.into_inner().to_string())
+        .replace("%DAY%", &puzzle.day.to_string())
+        .replace("%YEAR%", &puzzle.year.to_string())
+        .replace("%TITLE%", title)
+}
+
+/// What scaffolding did with a file.
+#[derive(Debug, PartialEq, Eq)]
+enum Outcome {
+    Created,
+    /// The file had contents, which were backed up to the path before replacing them.
+    Replaced(PathBuf),
+    /// The file had contents, which were left alone.
+    Kept,
+}
+
+/// Returns the first path of `<file>.bak`, `<file>.bak.1`, ... that does not exist yet.
+fn backup_path(path: &Path) -> PathBuf {
+    (0..)
+        .map(|i| {
+            let mut name = path.file_name().map(OsString::from).unwrap_or_default();
+            name.push(if i == 0 {
+                ".bak".to_string()
+            } else {
+                format!(".bak.{i}")
+            });
+            path.with_file_name(name)
+        })
+        .find(|backup| !backup.exists())
+        .unwrap()
+}
+
+/// Write a scaffolded file. A file that already has contents is kept, unless `replace` is set,
+/// in which case its contents are backed up before replacing them.
+fn scaffold_file(path: &Path, contents: &str, replace: bool) -> io::Result<Outcome> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    let has_contents = match fs::metadata(path) {
+        Ok(metadata) => metadata.len() > 0,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => false,
+        Err(e) => return Err(e),
+    };
+
+    if !has_contents {
+        fs::write(path, contents)?;
+        return Ok(Outcome::Created);
+    }
+
+    if !replace {
+        return Ok(Outcome::Kept);
+    }
+
+    let backup = backup_path(path);
+    fs::copy(path, &backup)?;
+    fs::write(path, contents)?;
+    Ok(Outcome::Replaced(backup))
+}
+
+/// Scaffold the files of a template for a puzzle. Inputs and examples with contents are never
+/// touched, `overwrite` only replaces the module file.
+pub fn handle(puzzle: PuzzleId, overwrite: bool, template: &str) {
+    let PuzzleId { year, day } = puzzle;
+
+    let files = match read_template(template) {
+        Ok(files) => files,
         Err(e) => {
-            eprintln!("Failed to create example file: {e}");
+            eprintln!("{e}");
             process::exit(1);
         }
+    };
+    let title = get_title(puzzle);
+
+    for (target, contents) in files {
+        let kind = target.kind();
+        let path = target.path(puzzle);
+        let contents = render(&contents, puzzle, &title);
+        let replace = overwrite && target == Target::Module;
+
+        match scaffold_file(&path, &contents, replace) {
+            Ok(Outcome::Created) if contents.is_empty() => {
+                println!("Created empty {kind} \"{}\"", path.display());
+            }
+            Ok(Outcome::Created) => {
+                println!("Created {kind} \"{}\"", path.display());
+            }
+            Ok(Outcome::Replaced(backup)) => {
+                println!(
+                    "Replaced {kind} \"{}\", backed up to \"{}\"",
+                    path.display(),
+                    backup.display()
+                );
+            }
+            Ok(Outcome::Kept) => {
+                println!("Left existing {kind} \"{}\" alone", path.display());
+            }
+            Err(e) => {
+                eprintln!("Failed to create {kind}: {e}");
+                process::exit(1);
+            }
+        }
     }
 
     println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    println!("🎄 Type `cargo solve {day} --year {year}` to run your solution.");
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use std::fs;
+
+    use super::{DEFAULT_TEMPLATE, Outcome, Target, handle, read_template, render, scaffold_file};
+    use crate::template::PuzzleId;
+    use crate::template::mock_server::{MockServer, Sandbox};
+    use crate::{day, year};
+
+    fn puzzle() -> PuzzleId {
+        PuzzleId::new(year!(2025), day!(5)).unwrap()
+    }
+
+    #[test]
+    fn creates_missing_files() {
+        let sandbox = Sandbox::new(&MockServer::builder().start());
+
+        handle(puzzle(), false, DEFAULT_TEMPLATE);
+
+        assert!(
+            sandbox
+                .read("rust/src/bin/2025-05.rs")
+                .contains("solution!(5, year = 2025)")
+        );
+        assert!(sandbox.exists("data/2025/inputs/05.txt"));
+        assert!(sandbox.exists("data/2025/examples/05.txt"));
+    }
+
+    #[test]
+    fn never_truncates_inputs_and_examples() {
+        let sandbox = Sandbox::new(&MockServer::builder().start());
+        fs::create_dir_all(sandbox.path("data/2025/inputs")).unwrap();
+        fs::create_dir_all(sandbox.path("data/2025/examples")).unwrap();
+        fs::write(sandbox.path("data/2025/inputs/05.txt"), "1\n2\n3\n").unwrap();
+        fs::write(sandbox.path("data/2025/examples/05.txt"), "1\n").unwrap();
+        fs::write(sandbox.path("rust/src/bin/2025-05.rs"), "// solved").unwrap();
+
+        handle(puzzle(), false, DEFAULT_TEMPLATE);
+        assert_eq!(sandbox.read("rust/src/bin/2025-05.rs"), "// solved");
+
+        handle(puzzle(), true, DEFAULT_TEMPLATE);
+        assert_eq!(sandbox.read("data/2025/inputs/05.txt"), "1\n2\n3\n");
+        assert_eq!(sandbox.read("data/2025/examples/05.txt"), "1\n");
+        assert!(
+            sandbox
+                .read("rust/src/bin/2025-05.rs")
+                .contains("solution!")
+        );
+        assert_eq!(sandbox.read("rust/src/bin/2025-05.rs.bak"), "// solved");
+    }
+
+    #[test]
+    fn backs_up_replaced_files() {
+        let sandbox = Sandbox::new(&MockServer::builder().start());
+        let path = sandbox.path("file.txt");
+
+        assert_eq!(scaffold_file(&path, "a", true).unwrap(), Outcome::Created);
+        assert_eq!(scaffold_file(&path, "b", false).unwrap(), Outcome::Kept);
+        assert_eq!(
+            scaffold_file(&path, "b", true).unwrap(),
+            Outcome::Replaced(sandbox.path("file.txt.bak"))
+        );
+        assert_eq!(
+            scaffold_file(&path, "c", true).unwrap(),
+            Outcome::Replaced(sandbox.path("file.txt.bak.1"))
+        );
+        assert_eq!(sandbox.read("file.txt"), "c");
+        assert_eq!(sandbox.read("file.txt.bak"), "a");
+        assert_eq!(sandbox.read("file.txt.bak.1"), "b");
+
+        fs::write(&path, "").unwrap();
+        assert_eq!(scaffold_file(&path, "d", false).unwrap(), Outcome::Created);
+    }
+
+    #[test]
+    fn maps_template_files() {
+        assert_eq!(Target::from_file_name("solution.rs"), Some(Target::Module));
+        assert_eq!(Target::from_file_name("input.txt"), Some(Target::Input));
+        assert_eq!(
+            Target::from_file_name("example.txt"),
+            Some(Target::Example(None))
+        );
+        assert_eq!(
+            Target::from_file_name("example-2.txt"),
+            Some(Target::Example(Some("2".into())))
+        );
+        assert_eq!(Target::from_file_name("example-.txt"), None);
+        assert_eq!(Target::from_file_name("README.md"), None);
+    }
+
+    #[test]
+    fn renders_placeholders() {
+        assert_eq!(
+            render(
+                "%TITLE% (%YEAR%/%DAY%): solution!(%DAY_NUMBER%)",
+                puzzle(),
+                "Day 5: Test"
+            ),
+            "Day 5: Test (2025/05): solution!(5)"
+        );
+    }
+
+    #[test]
+    fn reads_templates() {
+        for name in [
+            "default",
+            "grid",
+            "graph",
+            "lines-of-numbers",
+            "parse-then-solve",
+        ] {
+            let files = read_template(name).unwrap();
+            assert_eq!(files[0].0, Target::Module);
+            assert!(files.iter().any(|(target, _)| *target == Target::Input));
+            assert!(
+                files
+                    .iter()
+                    .any(|(target, _)| *target == Target::Example(None))
+            );
+        }
+        assert!(
+            read_template("missing")
+                .unwrap_err()
+                .contains("available templates: default, graph, grid")
+        );
+    }
+
+    #[test]
+    fn scaffolds_named_templates_with_title() {
+        let sandbox = Sandbox::new(&MockServer::builder().start());
+        fs::create_dir_all(sandbox.path("data/2025/puzzles")).unwrap();
+        fs::write(
+            sandbox.path("data/2025/puzzles/05.html"),
+            "<article class=\"day-desc\"><h2>--- Day 5: Test ---</h2></article>",
+        )
+        .unwrap();
+
+        handle(puzzle(), false, "grid");
+
+        let module = sandbox.read("rust/src/bin/2025-05.rs");
+        assert!(module.starts_with("//! # Day 5: Test\n"));
+        assert!(module.contains("pub fn parse(input: &str) -> Grid {"));
+        assert!(
+            sandbox
+                .read("data/2025/examples/05.txt")
+                .starts_with("---\n")
+        );
+    }
 }
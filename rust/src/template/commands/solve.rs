@@ -1,9 +1,16 @@
 use std::process::{Command, Stdio};
+use std::time::Duration;
 
-use crate::template::Day;
+use crate::template::{PuzzleId, crate_dir};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+pub fn handle(
+    puzzle: PuzzleId,
+    release: bool,
+    dhat: bool,
+    submit_part: Option<u8>,
+    timeout: Option<Duration>,
+) {
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), puzzle.to_string()];
 
     if dhat {
         cmd_args.extend([
@@ -23,8 +30,14 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         cmd_args.push(submit_part.to_string());
     }
 
+    if let Some(timeout) = timeout {
+        cmd_args.push("--timeout".to_string());
+        cmd_args.push(timeout.as_secs_f64().to_string());
+    }
+
     let mut cmd = Command::new("cargo")
         .args(&cmd_args)
+        .current_dir(crate_dir())
         .stdout(Stdio::inherit())
         .stderr(Stdio::inherit())
         .spawn()
@@ -0,0 +1,81 @@
+use std::process;
+
+use crate::template::aoc_client::{AocClient, AocClientError};
+use crate::template::stars::Stars;
+use crate::template::{Year, readme_stars};
+
+pub fn handle(year: Year, fetch: bool) {
+    let mut stars = match Stars::read_from_file(year) {
+        Ok(stars) => stars,
+        Err(e) => {
+            eprintln!("Could not read stars: {e}");
+            process::exit(1);
+        }
+    };
+
+    if fetch {
+        match fetch_stars(year) {
+            Ok(fetched) => stars.merge(&fetched),
+            Err(e) => {
+                eprintln!("failed to fetch stars: {e}");
+                process::exit(1);
+            }
+        }
+
+        if let Err(e) = stars.store_file(year) {
+            eprintln!("Failed to store stars: {e}");
+            process::exit(1);
+        }
+    }
+
+    match readme_stars::update(year, &stars) {
+        Ok(()) => println!("Updated stars table with {}⭐.", stars.total()),
+        Err(_) => {
+            eprintln!("Failed to update stars table.");
+            process::exit(1);
+        }
+    }
+}
+
+fn fetch_stars(year: Year) -> Result<Stars, AocClientError> {
+    let calendar = AocClient::from_env()?.get_calendar(year)?;
+    Ok(Stars::from_calendar(&calendar))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use std::fs;
+
+    use super::handle;
+    use crate::template::PuzzleId;
+    use crate::template::mock_server::{MockServer, Sandbox};
+    use crate::template::stars::Stars;
+    use crate::{day, year};
+
+    #[test]
+    fn fetches_stars_into_the_readme() {
+        let server = MockServer::builder()
+            .stars(PuzzleId::new(year!(2025), day!(1)).unwrap(), 2)
+            .stars(PuzzleId::new(year!(2025), day!(3)).unwrap(), 1)
+            .start();
+        let sandbox = Sandbox::new(&server);
+        fs::write(
+            sandbox.path("rust/README.md"),
+            "# aoc\n<!--- advent_readme_stars table --->\n",
+        )
+        .unwrap();
+
+        handle(year!(2025), true);
+
+        let stars = Stars::read_from_file(year!(2025)).unwrap();
+        assert_eq!(stars.get(day!(1)), 2);
+        assert_eq!(stars.get(day!(3)), 1);
+
+        let readme = sandbox.read("rust/README.md");
+        assert!(readme.contains("| [Day 1](https://adventofcode.com/2025/day/1) | ⭐ | ⭐ |"));
+        assert!(readme.contains("| [Day 3](https://adventofcode.com/2025/day/3) | ⭐ |   |"));
+        assert!(readme.contains("**Total: 3⭐**"));
+    }
+}
@@ -1,19 +1,58 @@
 use std::collections::HashSet;
+use std::process;
+use std::time::Duration;
 
-use crate::template::run_multi::run_multi;
-use crate::template::timings::Timings;
-use crate::template::{Day, all_days, readme_benchmarks};
+use crate::template::part_report::PARSE_PART;
+use crate::template::registry::Registry;
+use crate::template::run_multi::{Executor, run_multi};
+use crate::template::runner::RunOptions;
+use crate::template::timings::{Change, Metadata, Timings, current_profile};
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day, Year, all_days, git, readme_benchmarks};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
-    let stored_timings = Timings::read_from_file();
+/// Regression threshold of `--compare` in percent, unless `--threshold` is passed.
+pub const DEFAULT_THRESHOLD: f64 = 10.0;
+
+/// Options to compare new timings against stored ones.
+pub struct CompareOptions {
+    /// Git revision to compare against, e.g. a branch or commit. Defaults to the previous run.
+    pub baseline: Option<String>,
+    /// Slowdown in percent above which a part counts as a regression.
+    pub threshold: f64,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    registry: &Registry,
+    year: Year,
+    day: Option<Day>,
+    run_all: bool,
+    store: bool,
+    isolated: bool,
+    dhat: bool,
+    jobs: usize,
+    timeout: Option<Duration>,
+    compare: Option<CompareOptions>,
+) {
+    // NOTE: `--store` rewrites the timings file, never overwrite timings that could not be read.
+    let stored_timings = match Timings::read_from_file(year) {
+        Ok(timings) => timings,
+        Err(e) if store => {
+            eprintln!("Could not read stored timings, refusing to overwrite them: {e}");
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("Ignoring invalid stored timings: {e}");
+            Timings::default()
+        }
+    };
 
     let days_to_run = day.map_or_else(
         || {
             if run_all {
-                all_days().collect()
+                all_days(year).collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
+                all_days(year)
                     .filter(|day| !stored_timings.is_day_complete(*day))
                     .collect()
             }
@@ -21,20 +60,111 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    // NOTE: measuring the peak heap usage requires solutions built with `dhat-heap`.
+    let executor = if isolated || dhat {
+        Executor::Isolated {
+            is_release: true,
+            dhat,
+        }
+    } else {
+        Executor::InProcess(registry)
+    };
+
+    let options = RunOptions {
+        timed: true,
+        timeout,
+    };
+
+    let mut timings = run_multi(year, &days_to_run, &executor, options, jobs).unwrap();
+
+    // NOTE: isolated solutions are always built in release mode, or with the `dhat` profile.
+    let profile = if dhat {
+        "dhat"
+    } else if isolated {
+        "release"
+    } else {
+        current_profile()
+    };
+
+    let regressed = compare.is_some_and(|compare| {
+        let revision = compare.baseline.as_deref().map(|baseline| {
+            git::resolve_revision(baseline).unwrap_or_else(|| baseline.to_string())
+        });
+        let changes = stored_timings.compare(&timings, revision.as_deref(), Some(profile));
+        print_changes(&changes, revision.as_deref(), compare.threshold)
+    });
 
     if store {
-        let merged_timings = stored_timings.merge(&timings);
-        merged_timings.store_file().unwrap();
+        let merged_timings = if dhat {
+            // NOTE: the heap profiler slows solutions down, only store their peak heap usage.
+            for timing in &timings.data {
+                if !stored_timings.data.iter().any(|t| t.day == timing.day) {
+                    eprintln!(
+                        "Day {} has no stored timings, run `cargo time --store` before storing its peak heap usage.",
+                        timing.day
+                    );
+                }
+            }
+            stored_timings.merge_peak_bytes(&timings)
+        } else {
+            let metadata = Metadata::current(profile);
+            for timing in &mut timings.data {
+                timing.metadata.clone_from(&metadata);
+            }
+            stored_timings.merge(&timings)
+        };
+        merged_timings.store_file(year).unwrap();
 
         println!();
-        match readme_benchmarks::update(merged_timings) {
+        match readme_benchmarks::update(year, merged_timings) {
             Ok(()) => {
                 println!("Stored updated benchmarks.");
             }
-            Err(_) => {
-                eprintln!("Failed to store updated benchmarks.");
+            Err(e) => {
+                eprintln!("Failed to store updated benchmarks: {e}");
             }
         }
     }
+
+    if regressed {
+        eprintln!("Timings regressed by more than the threshold.");
+        process::exit(1);
+    }
+}
+
+/// Print the changes against the baseline and return whether any part regressed.
+fn print_changes(changes: &[Change], revision: Option<&str>, threshold: f64) -> bool {
+    println!();
+    match revision {
+        Some(revision) => println!("{ANSI_BOLD}Compared to {revision}{ANSI_RESET}"),
+        None => println!("{ANSI_BOLD}Compared to the previous run{ANSI_RESET}"),
+    }
+
+    if changes.is_empty() {
+        println!("No stored timings to compare against.");
+        return false;
+    }
+
+    let mut regressed = false;
+
+    for change in changes {
+        let percent = change.percent();
+        let step = match change.part {
+            PARSE_PART => "Parse".to_string(),
+            part => format!("Part {part}"),
+        };
+        let verdict = if percent > threshold {
+            regressed = true;
+            format!(" ✘ regressed by more than {threshold}%")
+        } else {
+            String::new()
+        };
+
+        println!(
+            "Day {} {step}: {:.1?} → {:.1?} ({percent:+.1}%){verdict}",
+            change.day, change.baseline, change.current
+        );
+    }
+
+    regressed
 }
@@ -0,0 +1,52 @@
+use crate::template::PuzzleId;
+use crate::template::commands::{download, read, scaffold};
+
+/// Scaffold a solution for the puzzle of today, download its input and read its description.
+pub fn handle(puzzle: PuzzleId) {
+    // NOTE: download first, so the scaffolded module can use the title of the puzzle.
+    download::handle(puzzle);
+    scaffold::handle(puzzle, false, scaffold::DEFAULT_TEMPLATE);
+    read::handle(puzzle);
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::handle;
+    use crate::template::PuzzleId;
+    use crate::template::mock_server::{MockServer, Sandbox};
+    use crate::{day, year};
+
+    #[test]
+    fn scaffolds_downloads_and_reads_the_puzzle() {
+        let puzzle = PuzzleId::new(year!(2025), day!(1)).unwrap();
+        let server = MockServer::builder()
+            .puzzle(
+                puzzle,
+                "<article class=\"day-desc\"><h2>--- Day 1: Test ---</h2><p>For example:</p>\
+                 <pre><code>1\n2\n</code></pre><p>This sums to <code><em>3</em></code>.</p></article>",
+            )
+            .input(puzzle, "1\n2\n3\n")
+            .start();
+        let sandbox = Sandbox::new(&server);
+
+        handle(puzzle);
+
+        assert!(
+            sandbox
+                .read("rust/src/bin/2025-01.rs")
+                .contains("solution!(1, year = 2025)")
+        );
+        assert_eq!(
+            sandbox.read("data/2025/examples/01.txt"),
+            "---\npart_1: 3\n---\n1\n2\n"
+        );
+        assert_eq!(sandbox.read("data/2025/inputs/01.txt"), "1\n2\n3\n");
+        assert!(
+            sandbox
+                .read("data/2025/puzzles/01.html")
+                .contains("--- Day 1: Test ---")
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use std::process;
+
+use crate::template::answers::{Answers, Verdict};
+use crate::template::part_report::PARSE_PART;
+use crate::template::registry::Registry;
+use crate::template::runner::RunOptions;
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day, PuzzleId, Year, all_days, try_read_file};
+
+/// Run solutions with known answers and check their results.
+/// Exits with a non-zero status code if any result does not match its known answer.
+pub fn handle(registry: &Registry, year: Year, day: Option<Day>) {
+    let answers = match Answers::read_from_file(year) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("Could not read known answers: {e}");
+            process::exit(1);
+        }
+    };
+
+    let mut verified = 0;
+    let mut failures: Vec<String> = vec![];
+
+    // NOTE: days without known answers are skipped, there is nothing to verify.
+    let days: Vec<Day> = all_days(year)
+        .filter(|d| day.is_none_or(|day| day == *d))
+        .filter(|day| answers.data.contains_key(day))
+        .collect();
+
+    if days.is_empty() {
+        println!("No known answers to verify.");
+        return;
+    }
+
+    for (i, day) in days.into_iter().enumerate() {
+        let Some(puzzle) = PuzzleId::new(year, day) else {
+            continue;
+        };
+
+        if i > 0 {
+            println!();
+        }
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        println!("------");
+
+        let Some(solution) = registry.get(puzzle) else {
+            println!("No solution.");
+            failures.push(format!("Day {day}: no solution."));
+            continue;
+        };
+
+        let input = match try_read_file("inputs", puzzle) {
+            Ok(input) => input,
+            Err(e) => {
+                println!("Could not read input file: {e}");
+                failures.push(format!("Day {day}: could not read input file."));
+                continue;
+            }
+        };
+
+        let reports = (solution.run)(&input, RunOptions::default());
+
+        for part in [1, 2] {
+            let report = reports.iter().find(|report| report.part == part);
+            let answer = report.and_then(|report| report.answer.as_deref());
+            let error = reports
+                .iter()
+                .filter(|report| report.part == part || report.part == PARSE_PART)
+                .find_map(|report| report.error.as_deref());
+
+            match answers.check(day, part, answer) {
+                Verdict::Unknown => {}
+                Verdict::Correct => verified += 1,
+                Verdict::Incorrect { expected } => failures.push(match error {
+                    Some(error) => format!("Day {day}, part {part}: expected {expected}, {error}."),
+                    None => format!(
+                        "Day {day}, part {part}: expected {expected}, got {}.",
+                        answer.unwrap_or("nothing")
+                    ),
+                }),
+            }
+        }
+    }
+
+    println!();
+    if failures.is_empty() {
+        println!("{ANSI_BOLD}Verified {verified} answer(s).{ANSI_RESET}");
+    } else {
+        println!(
+            "{ANSI_BOLD}Verified {verified} answer(s), {} failure(s):{ANSI_RESET}",
+            failures.len()
+        );
+        for failure in &failures {
+            println!("  {failure}");
+        }
+        process::exit(1);
+    }
+}
@@ -0,0 +1,80 @@
+/// Resolution of the crate directory, which holds the solutions and the readme, and of the data
+/// directory, which holds the inputs, examples, puzzles and other data of every year in `<year>/`
+/// subdirectories.
+///
+/// The build script includes this module as well, so it must only depend on `std`.
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
+
+// NOTE: the directories can only be redirected by tests, release builds always resolve them from
+// `AOC_DATA_DIR` and the manifest directory.
+#[cfg(feature = "test_lib")]
+thread_local! {
+    /// Crate directory of the current thread, if redirected via [`redirect_crate_dir`].
+    static CRATE_DIR: std::cell::RefCell<Option<PathBuf>> = const { std::cell::RefCell::new(None) };
+    /// Data directory of the current thread, if redirected via [`redirect_data_dir`].
+    static DATA_DIR: std::cell::RefCell<Option<PathBuf>> = const { std::cell::RefCell::new(None) };
+}
+
+/// Returns the crate directory, i.e. `$CARGO_MANIFEST_DIR`, so that commands work from any
+/// working directory.
+pub fn crate_dir() -> PathBuf {
+    #[cfg(feature = "test_lib")]
+    if let Some(dir) = CRATE_DIR.with_borrow(Clone::clone) {
+        return dir;
+    }
+
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+}
+
+/// Redirects the crate directory of the current thread, e.g. to a temporary directory in tests.
+/// Other threads, including threads spawned by the current one, are not affected.
+#[cfg(feature = "test_lib")]
+pub fn redirect_crate_dir(dir: Option<PathBuf>) {
+    CRATE_DIR.set(dir);
+}
+
+/// Environment variable that overrides the data directory.
+pub const DATA_DIR_ENV: &str = "AOC_DATA_DIR";
+
+/// Returns the data directory. In order of precedence, this is
+///  - in tests, the directory the current thread was redirected to via `redirect_data_dir`,
+///  - the directory set via `AOC_DATA_DIR`, relative to the current directory,
+///  - the `data` directory next to the crate, i.e. `$CARGO_MANIFEST_DIR/../data`,
+///  - the first `data` directory found searching upward from the current directory.
+///
+/// If no data directory exists yet, the one next to the crate is returned, so it can be created.
+pub fn data_dir() -> PathBuf {
+    #[cfg(feature = "test_lib")]
+    if let Some(dir) = DATA_DIR.with_borrow(Clone::clone) {
+        return dir;
+    }
+
+    if let Some(dir) = env::var_os(DATA_DIR_ENV).filter(|dir| !dir.is_empty()) {
+        return PathBuf::from(dir);
+    }
+
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let crate_data_dir = manifest_dir.parent().unwrap_or(manifest_dir).join("data");
+    if crate_data_dir.is_dir() {
+        return crate_data_dir;
+    }
+
+    env::current_dir()
+        .ok()
+        .and_then(|cwd| {
+            cwd.ancestors()
+                .map(|dir| dir.join("data"))
+                .find(|dir| dir.is_dir())
+        })
+        .unwrap_or(crate_data_dir)
+}
+
+/// Redirects the data directory of the current thread, e.g. to a temporary directory in tests.
+/// Other threads, including threads spawned by the current one, are not affected.
+#[cfg(feature = "test_lib")]
+pub fn redirect_data_dir(dir: Option<PathBuf>) {
+    DATA_DIR.set(dir);
+}
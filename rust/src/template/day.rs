@@ -2,13 +2,10 @@ use std::error::Error;
 use std::fmt::Display;
 use std::str::FromStr;
 
-#[cfg(feature = "today")]
-use chrono::{Datelike, FixedOffset, Utc};
+use crate::template::Year;
 
-#[cfg(feature = "today")]
-const SERVER_UTC_OFFSET: i32 = -5;
-
-/// A valid day number of advent (i.e. an integer in range 1 to 25).
+/// A day number of advent (i.e. an integer in range 1 to 25). The year decides which days have a
+/// puzzle, e.g. only days 1 to 12 from 2025 on, see [`Day::exists_in`].
 ///
 /// # Display
 /// This value displays as a two digit number.
@@ -22,8 +19,8 @@ const SERVER_UTC_OFFSET: i32 = -5;
 pub struct Day(u8);
 
 impl Day {
-    /// Creates a [`Day`] from the provided value if it's in the valid range,
-    /// returns [`None`] otherwise.
+    /// Creates a [`Day`] from the provided value if it's in range 1 to 25, returns [`None`]
+    /// otherwise. Whether the day has a puzzle depends on the year, see [`Day::exists_in`].
     pub const fn new(day: u8) -> Option<Self> {
         if day == 0 || day > 25 {
             return None;
@@ -35,19 +32,11 @@ impl Day {
     pub fn into_inner(self) -> u8 {
         self.0
     }
-}
 
-#[cfg(feature = "today")]
-impl Day {
-    /// Returns the current day if it's between the 1st and the 25th of december, `None` otherwise.
-    pub fn today() -> Option<Self> {
-        let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
-        let today = Utc::now().with_timezone(&offset);
-        if today.month() == 12 && today.day() <= 25 {
-            Self::new(u8::try_from(today.day()).ok()?)
-        } else {
-            None
-        }
+    /// Returns `true` if the [`Day`] has a puzzle in the provided year.
+    #[must_use]
+    pub const fn exists_in(self, year: Year) -> bool {
+        self.0 <= year.last_day()
     }
 }
 
@@ -88,26 +77,29 @@ impl Error for DayFromStrError {}
 
 impl Display for DayFromStrError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str("expecting a day number between 1 and 25")
+        f.write_str("expecting a day number between 1 and 25 (1 and 12 from 2025 on)")
     }
 }
 
 /* -------------------------------------------------------------------------- */
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
-pub fn all_days() -> AllDays {
-    AllDays::new()
+/// An iterator that yields every day of advent in the provided year.
+pub fn all_days(year: Year) -> AllDays {
+    AllDays::new(year)
 }
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// An iterator that yields every day of advent from the 1st to the last day of a year.
 pub struct AllDays {
     current: u8,
+    last: u8,
 }
 
 impl AllDays {
-    #[allow(clippy::new_without_default)]
-    pub fn new() -> Self {
-        Self { current: 1 }
+    pub fn new(year: Year) -> Self {
+        Self {
+            current: 1,
+            last: year.last_day(),
+        }
     }
 }
 
@@ -115,10 +107,10 @@ impl Iterator for AllDays {
     type Item = Day;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current > 25 {
+        if self.current > self.last {
             return None;
         }
-        // NOTE: the iterator starts at 1 and we have verified that the value is not above 25.
+        // NOTE: the iterator starts at 1 and `last` is never above 25.
         let day = Day(self.current);
         self.current += 1;
 
@@ -128,26 +120,44 @@ impl Iterator for AllDays {
 
 /* -------------------------------------------------------------------------- */
 
-/// Creates a [`Day`] value in a const context.
+/// Creates a [`Day`] value in a const context. The day is checked against the year by
+/// [`PuzzleId::new`](crate::template::PuzzleId::new).
 #[macro_export]
 macro_rules! day {
     ($day:expr) => {
         const {
-            $crate::template::Day::new($day)
-                .expect("invalid day number, expecting a value between 1 and 25")
+            $crate::template::Day::new($day).expect(
+                "invalid day number, expecting a value between 1 and 25 (1 and 12 from 2025 on)",
+            )
         }
     };
 }
 
 /* -------------------------------------------------------------------------- */
 
-#[cfg(feature = "test_lib")]
+#[cfg(all(test, feature = "test_lib"))]
 mod tests {
     use super::{Day, all_days};
+    use crate::year;
+
+    #[test]
+    fn day_exists_in_year() {
+        assert!(Day(12).exists_in(year!(2025)));
+        assert!(!Day(13).exists_in(year!(2025)));
+        assert!(Day(25).exists_in(year!(2024)));
+    }
+
+    #[test]
+    fn all_days_iterator_short_year() {
+        let days: Vec<_> = all_days(year!(2025)).collect();
+        assert_eq!(days.len(), 12);
+        assert_eq!(days.first(), Some(&Day(1)));
+        assert_eq!(days.last(), Some(&Day(12)));
+    }
 
     #[test]
     fn all_days_iterator() {
-        let mut iter = all_days();
+        let mut iter = all_days(year!(2024));
 
         assert_eq!(iter.next(), Some(Day(1)));
         assert_eq!(iter.next(), Some(Day(2)));
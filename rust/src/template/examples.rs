@@ -0,0 +1,369 @@
+//! Examples with declared answers, which are checked by tests generated for every example.
+//!
+//! A day can have any number of examples in `data/<year>/examples`, named `<day>.txt` or
+//! `<day>-<name>.txt`. The front matter of an example declares the expected answers as `part_1`
+//! and `part_2`, `ignore` marks its tests as ignored with a reason, and every other entry
+//! overrides a parameter of the solution:
+//!
+//! ```text
+//! ---
+//! part_1: 40
+//! part_2: 25272
+//! connections: 10
+//! ---
+//! 162,817,812
+//! ```
+//!
+//! The build script generates a test for every declared answer, which solutions include via
+//! [`example_tests!`](crate::example_tests).
+//!
+//! Examples and their answers are extracted from downloaded puzzle descriptions, see
+//! [`extract_from_puzzle`].
+use std::{fs, io, path::PathBuf};
+
+use crate::template::front_matter::{FrontMatter, split_front_matter};
+use crate::template::runner::PartOutcome;
+use crate::template::{PuzzleId, aoc_client, data_path, html};
+
+/// An example input with the answers and parameters of its front matter.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Example<'a> {
+    pub input: &'a str,
+    pub answers: [Option<&'a str>; 2],
+    /// Reason why the tests of the example are ignored, e.g. a solution that only holds for the
+    /// real input.
+    pub ignore: Option<&'a str>,
+    pub params: FrontMatter<'a>,
+}
+
+impl<'a> Example<'a> {
+    pub fn parse(contents: &'a str) -> Result<Self, String> {
+        let (entries, input) = split_front_matter(contents)?;
+        let mut answers = [None; 2];
+        let mut ignore = None;
+        let mut params = Vec::new();
+
+        for (key, value) in entries {
+            match key {
+                "part_1" => answers[0] = Some(value),
+                "part_2" => answers[1] = Some(value),
+                "ignore" => ignore = Some(value),
+                _ => params.push((key, value)),
+            }
+        }
+
+        Ok(Self {
+            input,
+            answers,
+            ignore,
+            params,
+        })
+    }
+
+    /// Returns the declared answer of a part.
+    pub fn answer(&self, part: u8) -> Option<&'a str> {
+        match part {
+            1 | 2 => self.answers[usize::from(part) - 1],
+            _ => None,
+        }
+    }
+}
+
+/// Solve a part of an example and check it against the answer declared in its front matter.
+/// Used by the generated example tests, `solve` is defined by `solution!`.
+pub fn check(
+    name: &str,
+    contents: &str,
+    part: u8,
+    solve: impl FnOnce(&Example, u8) -> PartOutcome,
+) {
+    let example =
+        Example::parse(contents).unwrap_or_else(|e| panic!("invalid front matter in {name}: {e}"));
+    let expected = example
+        .answer(part)
+        .unwrap_or_else(|| panic!("{name} declares no answer for part {part}."));
+
+    match solve(&example, part) {
+        PartOutcome::Solved(answer) => {
+            assert_eq!(answer, expected, "wrong answer for part {part} of {name}");
+        }
+        PartOutcome::Unsolved => {
+            panic!("part {part} of {name} returned no answer, expected {expected}.")
+        }
+        PartOutcome::Failed(error) => panic!("part {part} of {name} failed: {error}"),
+    }
+}
+
+/// Fill an example in with an extracted input and answers. The input is only used if the example
+/// has none. Answers are only added to an example with the extracted input, for parts that do
+/// not declare an answer yet. Returns `None` if nothing changed.
+fn merge_example(
+    existing: &str,
+    input: Option<&str>,
+    answers: [Option<&str>; 2],
+) -> Result<Option<String>, String> {
+    let example = Example::parse(existing)?;
+    let header = &existing[..existing.len() - example.input.len()];
+    let mut changed = false;
+
+    let body = match input {
+        Some(input) if example.input.trim().is_empty() => {
+            changed = true;
+            input
+        }
+        _ => example.input,
+    };
+
+    let mut lines: Vec<String> = header.lines().map(String::from).collect();
+    if lines.is_empty() {
+        lines = vec!["---".into(), "---".into()];
+    }
+
+    if input.is_some_and(|input| input.trim() == body.trim()) {
+        for (part, answer) in (1..=2).zip(answers) {
+            let Some(answer) = answer else {
+                continue;
+            };
+            if example.answer(part).is_some() {
+                continue;
+            }
+
+            // NOTE: replaces the placeholder of the scaffold templates, e.g. `# part_1:`.
+            let key = format!("part_{part}:");
+            lines.retain(|line| line.trim().trim_start_matches('#').trim() != key);
+            lines.insert(lines.len() - 1, format!("{key} {answer}"));
+            changed = true;
+        }
+    }
+
+    if !changed {
+        return Ok(None);
+    }
+
+    let mut contents = String::new();
+    if !header.is_empty() || lines.len() > 2 {
+        contents.push_str(&lines.join("\n"));
+        contents.push('\n');
+    }
+    contents.push_str(body);
+    Ok(Some(contents))
+}
+
+/// Extract the examples and their answers from the downloaded description of a puzzle into the
+/// examples of its day, `<day>.txt`. If part two has an example of its own, it is extracted to
+/// `<day>-2.txt`. Inputs and answers that the examples already have are kept.
+///
+/// Returns the paths of the examples that changed.
+pub fn extract_from_puzzle(puzzle: PuzzleId) -> Result<Vec<PathBuf>, String> {
+    let PuzzleId { year, day } = puzzle;
+    let description = fs::read_to_string(aoc_client::get_puzzle_path(puzzle))
+        .map_err(|e| format!("could not read puzzle description: {e}"))?;
+
+    let parts = html::extract_examples(&description);
+    let part_one = parts.first().cloned().unwrap_or_default();
+
+    let mut targets = vec![(
+        day.to_string(),
+        part_one.input.clone(),
+        [part_one.answer, None],
+    )];
+
+    if let Some(part_two) = parts.get(1) {
+        match &part_two.input {
+            Some(input) if part_one.input.as_ref() != Some(input) => targets.push((
+                format!("{day}-2"),
+                Some(input.clone()),
+                [None, part_two.answer.clone()],
+            )),
+            _ => targets[0].2[1] = part_two.answer.clone(),
+        }
+    }
+
+    let mut changed = vec![];
+
+    for (name, input, answers) in targets {
+        let path = data_path(year, format!("examples/{name}.txt"));
+        let existing = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e.to_string()),
+        };
+
+        let merged = merge_example(
+            &existing,
+            input.as_deref(),
+            answers.each_ref().map(Option::as_deref),
+        )
+        .map_err(|e| format!("invalid front matter in {}: {e}", path.display()))?;
+
+        if let Some(contents) = merged {
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+            }
+            fs::write(&path, contents).map_err(|e| e.to_string())?;
+            changed.push(path);
+        }
+    }
+
+    Ok(changed)
+}
+
+/// Includes the tests that the build script generates for the examples of a solution, one test
+/// per example and declared answer. Invoke it next to `solution!`, at the root of the solution.
+#[macro_export]
+macro_rules! example_tests {
+    () => {
+        #[cfg(test)]
+        mod example_tests {
+            include!(concat!(
+                env!("OUT_DIR"),
+                "/examples/",
+                env!("CARGO_BIN_NAME"),
+                ".rs"
+            ));
+        }
+    };
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use std::fs;
+
+    use super::{Example, check, extract_from_puzzle, merge_example};
+    use crate::template::PuzzleId;
+    use crate::template::mock_server::{MockServer, Sandbox};
+    use crate::template::runner::PartOutcome;
+    use crate::{day, year};
+
+    const CONTENTS: &str = "---\npart_1: 40\nconnections: 10\n---\n1,2\n";
+
+    #[test]
+    fn parses_examples() {
+        let example = Example::parse(CONTENTS).unwrap();
+        assert_eq!(example.input, "1,2\n");
+        assert_eq!(example.answer(1), Some("40"));
+        assert_eq!(example.answer(2), None);
+        assert_eq!(example.params, vec![("connections", "10")]);
+        assert_eq!(example.ignore, None);
+
+        let example = Example::parse("1,2\n").unwrap();
+        assert_eq!(example.input, "1,2\n");
+        assert_eq!(example.answers, [None, None]);
+
+        let example = Example::parse("---\npart_1: 2\nignore: too slow\n---\n1,2\n").unwrap();
+        assert_eq!(example.ignore, Some("too slow"));
+        assert!(example.params.is_empty());
+    }
+
+    #[test]
+    fn checks_answers() {
+        check("08.txt", CONTENTS, 1, |example, part| {
+            assert_eq!(part, 1);
+            assert_eq!(example.input, "1,2\n");
+            PartOutcome::Solved("40".into())
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "wrong answer for part 1 of 08.txt")]
+    fn fails_on_wrong_answers() {
+        check("08.txt", CONTENTS, 1, |_, _| {
+            PartOutcome::Solved("41".into())
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "part 1 of 08.txt returned no answer, expected 40.")]
+    fn fails_on_unsolved_parts() {
+        check("08.txt", CONTENTS, 1, |_, _| PartOutcome::Unsolved);
+    }
+
+    #[test]
+    #[should_panic(expected = "08.txt declares no answer for part 2.")]
+    fn fails_on_undeclared_answers() {
+        check("08.txt", CONTENTS, 2, |_, _| PartOutcome::Unsolved);
+    }
+
+    #[test]
+    fn fills_in_empty_examples() {
+        assert_eq!(
+            merge_example("", Some("1\n2\n"), [Some("3"), None]).unwrap(),
+            Some("---\npart_1: 3\n---\n1\n2\n".into())
+        );
+        assert_eq!(
+            merge_example("", Some("1\n2\n"), [None, None]).unwrap(),
+            Some("1\n2\n".into())
+        );
+
+        let scaffolded = "---\n# Declare the answers.\n# part_1:\n# part_2:\n---\n";
+        assert_eq!(
+            merge_example(scaffolded, Some("1\n"), [Some("3"), None]).unwrap(),
+            Some("---\n# Declare the answers.\n# part_2:\npart_1: 3\n---\n1\n".into())
+        );
+    }
+
+    #[test]
+    fn keeps_existing_inputs_and_answers() {
+        let existing = "---\npart_1: 4\nconnections: 10\n---\n1\n";
+        assert_eq!(
+            merge_example(existing, Some("1\n"), [Some("3"), None]).unwrap(),
+            None
+        );
+        assert_eq!(
+            merge_example(existing, Some("1\n"), [Some("3"), Some("5")]).unwrap(),
+            Some("---\npart_1: 4\nconnections: 10\npart_2: 5\n---\n1\n".into())
+        );
+        // NOTE: answers of an extracted example do not apply to a different input.
+        assert_eq!(
+            merge_example("2\n", Some("1\n"), [Some("3"), Some("5")]).unwrap(),
+            None
+        );
+    }
+
+    fn extract_fixture(fixture: &str) -> (Sandbox, Vec<String>) {
+        let sandbox = Sandbox::new(&MockServer::builder().start());
+        fs::create_dir_all(sandbox.path("data/2025/puzzles")).unwrap();
+        fs::write(sandbox.path("data/2025/puzzles/01.html"), fixture).unwrap();
+
+        let puzzle = PuzzleId::new(year!(2025), day!(1)).unwrap();
+        let changed = extract_from_puzzle(puzzle)
+            .unwrap()
+            .iter()
+            .map(|path| path.file_name().unwrap().to_string_lossy().to_string())
+            .collect();
+        (sandbox, changed)
+    }
+
+    #[test]
+    fn extracts_examples_from_puzzles() {
+        let (sandbox, changed) = extract_fixture(include_str!("fixtures/two-parts.html"));
+        assert_eq!(changed, vec!["01.txt"]);
+        assert_eq!(
+            sandbox.read("data/2025/examples/01.txt"),
+            "---\npart_1: 5\npart_2: 2\n---\n#.#\n.#.\n<#>\n"
+        );
+    }
+
+    #[test]
+    fn extracts_separate_examples_of_part_two() {
+        let (sandbox, changed) = extract_fixture(include_str!("fixtures/separate-examples.html"));
+        assert_eq!(changed, vec!["01.txt", "01-2.txt"]);
+        assert_eq!(
+            sandbox.read("data/2025/examples/01.txt"),
+            "---\npart_1: 2\n---\na -> b\nb -> c\n"
+        );
+        assert_eq!(
+            sandbox.read("data/2025/examples/01-2.txt"),
+            "---\npart_2: 6\n---\na -> b\nb -> c\nc -> a\n"
+        );
+    }
+
+    #[test]
+    fn skips_puzzles_without_examples() {
+        let (sandbox, changed) = extract_fixture(include_str!("fixtures/no-example.html"));
+        assert!(changed.is_empty());
+        assert!(!sandbox.exists("data/2025/examples/01.txt"));
+    }
+}
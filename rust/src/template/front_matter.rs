@@ -0,0 +1,89 @@
+//! Front matters of inputs, a block of `key: value` lines between two `---` lines at the start
+//! of the file, which declare the answers and parameters of examples:
+//!
+//! ```text
+//! ---
+//! part_1: 40
+//! connections: 10
+//! ---
+//! 162,817,812
+//! ```
+//!
+//! The build script includes this module as well, so it must only depend on `std`.
+
+/// The `key: value` entries of a front matter.
+pub type FrontMatter<'a> = Vec<(&'a str, &'a str)>;
+
+/// Splits the front matter off an input, returning its `key: value` entries and the input.
+/// Empty lines and lines starting with `#` in the front matter are skipped.
+/// An input without front matter is returned as is.
+pub fn split_front_matter(contents: &str) -> Result<(FrontMatter<'_>, &str), String> {
+    let mut lines = contents.split_inclusive('\n');
+    if lines.next().map(str::trim_end) != Some("---") {
+        return Ok((Vec::new(), contents));
+    }
+
+    let mut entries = Vec::new();
+    let mut offset = contents.find('\n').map_or(contents.len(), |i| i + 1);
+
+    for line in lines {
+        offset += line.len();
+        let line = line.trim();
+
+        if line == "---" {
+            return Ok((entries, &contents[offset..]));
+        }
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (key, value) = line.split_once(':').ok_or(format!(
+            "expected `key: value` in front matter, found `{line}`."
+        ))?;
+        entries.push((key.trim(), value.trim()));
+    }
+
+    Err("front matter is not closed by a `---` line.".into())
+}
+
+/// Returns an input without its front matter. If the front matter is malformed, the input is
+/// returned as is.
+pub fn strip_front_matter(contents: &str) -> &str {
+    split_front_matter(contents).map_or(contents, |(_, input)| input)
+}
+
+/* -------------------------------------------------------------------------- */
+
+// NOTE: the build script includes this module as well, but has no test harness.
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{split_front_matter, strip_front_matter};
+
+    #[test]
+    fn splits_front_matter() {
+        let contents = "---\nconnections: 10\n# a comment\n\nskip : false\n---\n1,2\n3,4\n";
+        let (entries, input) = split_front_matter(contents).unwrap();
+        assert_eq!(entries, vec![("connections", "10"), ("skip", "false")]);
+        assert_eq!(input, "1,2\n3,4\n");
+    }
+
+    #[test]
+    fn keeps_inputs_without_front_matter() {
+        let contents = "1,2\n---\n3,4\n";
+        assert_eq!(split_front_matter(contents).unwrap(), (vec![], contents));
+        assert_eq!(strip_front_matter(contents), contents);
+    }
+
+    #[test]
+    fn handles_crlf_front_matter() {
+        let (entries, input) = split_front_matter("---\r\nskip: false\r\n---\r\n1,2").unwrap();
+        assert_eq!(entries, vec![("skip", "false")]);
+        assert_eq!(input, "1,2");
+    }
+
+    #[test]
+    fn rejects_malformed_front_matter() {
+        assert!(split_front_matter("---\nconnections 10\n---\n1,2").is_err());
+        assert!(split_front_matter("---\nconnections: 10\n1,2").is_err());
+    }
+}
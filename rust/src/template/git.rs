@@ -0,0 +1,51 @@
+/// Helpers to query the git repository the solutions live in.
+use std::{path::Path, process::Command};
+
+/// Run git in the directory of the crate and return its trimmed output, or its error message.
+fn git(args: &[&str]) -> Result<String, String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .map_err(|e| format!("could not run git: {e}"))?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+/// Returns the abbreviated hash of a revision, e.g. `HEAD`, a branch or a tag.
+pub fn resolve_revision(name: &str) -> Option<String> {
+    git(&["rev-parse", "--short", &format!("{name}^{{commit}}")]).ok()
+}
+
+/// Returns the abbreviated hash of the checked out revision, suffixed with `-dirty` if tracked
+/// files have uncommitted changes.
+pub fn current_revision() -> Option<String> {
+    let revision = resolve_revision("HEAD")?;
+    let dirty = git(&["status", "--porcelain", "--untracked-files=no"])
+        .is_ok_and(|status| !status.is_empty());
+    Some(if dirty {
+        format!("{revision}-dirty")
+    } else {
+        revision
+    })
+}
+
+/// Returns the path of the crate relative to the root of the repository, e.g. `rust/`.
+pub fn crate_prefix() -> Result<String, String> {
+    git(&["rev-parse", "--show-prefix"])
+}
+
+/// Check out a revision into a new worktree, detached from any branch.
+pub fn add_worktree(path: &Path, revision: &str) -> Result<(), String> {
+    let path = path.to_string_lossy();
+    git(&["worktree", "add", "--detach", "--force", &path, revision]).map(|_| ())
+}
+
+/// Remove a worktree, discarding any changes in it.
+pub fn remove_worktree(path: &Path) -> Result<(), String> {
+    git(&["worktree", "remove", "--force", &path.to_string_lossy()]).map(|_| ())
+}
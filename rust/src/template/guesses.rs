@@ -0,0 +1,370 @@
+/// History of submitted answers and their verdicts, used to refuse submissions locally that
+/// are known to be wrong instead of spending a rate-limited attempt.
+///
+/// Guesses are stored per year in `data/<year>/guesses.json`, keyed by day and part:
+/// `{ "09": { "part_2": [{ "answer": "122383625", "verdict": "too_low" }] } }`.
+use std::{collections::HashMap, fmt::Display, fs, io, path::PathBuf, str::FromStr};
+
+use tinyjson::JsonValue;
+
+use crate::template::{Day, Year, data_path};
+
+fn get_guesses_path(year: Year) -> PathBuf {
+    data_path(year, "guesses.json")
+}
+
+/// The verdict of the site on a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SubmitVerdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// The answer is wrong, without a hint in which direction.
+    Wrong,
+}
+
+impl SubmitVerdict {
+    /// Parses the verdict from the response to a submission.
+    /// Returns `None` if the answer was not checked, e.g. because of rate limiting.
+    pub fn from_response(response: &str) -> Option<Self> {
+        if response.contains("That's the right answer") {
+            Some(SubmitVerdict::Correct)
+        } else if response.contains("That's not the right answer") {
+            if response.contains("too high") {
+                Some(SubmitVerdict::TooHigh)
+            } else if response.contains("too low") {
+                Some(SubmitVerdict::TooLow)
+            } else {
+                Some(SubmitVerdict::Wrong)
+            }
+        } else {
+            None
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            SubmitVerdict::Correct => "correct",
+            SubmitVerdict::TooHigh => "too_high",
+            SubmitVerdict::TooLow => "too_low",
+            SubmitVerdict::Wrong => "wrong",
+        }
+    }
+}
+
+impl Display for SubmitVerdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.as_str().replace('_', " "))
+    }
+}
+
+impl FromStr for SubmitVerdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(SubmitVerdict::Correct),
+            "too_high" => Ok(SubmitVerdict::TooHigh),
+            "too_low" => Ok(SubmitVerdict::TooLow),
+            "wrong" => Ok(SubmitVerdict::Wrong),
+            s => Err(format!("Unknown verdict `{s}`.")),
+        }
+    }
+}
+
+/// A submitted answer and its verdict.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Guess {
+    pub answer: String,
+    pub verdict: SubmitVerdict,
+}
+
+/// Guesses for both parts of a single day, in the order they were submitted.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DayGuesses {
+    pub part_1: Vec<Guess>,
+    pub part_2: Vec<Guess>,
+}
+
+/// Guesses for all days of a year.
+#[derive(Clone, Debug, Default)]
+pub struct Guesses {
+    pub data: HashMap<Day, DayGuesses>,
+}
+
+impl Guesses {
+    /// Read the guesses of a year. If the file is not present, returns empty guesses.
+    pub fn read_from_file(year: Year) -> Result<Self, String> {
+        match fs::read_to_string(get_guesses_path(year)) {
+            Ok(contents) => Guesses::from_str(&contents),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Guesses::default()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// Write the guesses of a year to its file.
+    pub fn store_file(&self, year: Year) -> io::Result<()> {
+        let path = get_guesses_path(year);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = fs::File::create(path)?;
+        JsonValue::from(self).format_to(&mut file)
+    }
+
+    /// Returns the guesses of a part, in the order they were submitted.
+    pub fn get(&self, day: Day, part: u8) -> &[Guess] {
+        match (self.data.get(&day), part) {
+            (Some(guesses), 1) => &guesses.part_1,
+            (Some(guesses), 2) => &guesses.part_2,
+            _ => &[],
+        }
+    }
+
+    /// Record the verdict of a submitted answer.
+    pub fn record(&mut self, day: Day, part: u8, guess: Guess) {
+        let guesses = self.data.entry(day).or_default();
+        match part {
+            1 => guesses.part_1.push(guess),
+            2 => guesses.part_2.push(guess),
+            _ => {}
+        }
+    }
+
+    /// Check whether an answer is worth submitting, given the previous guesses of a part.
+    /// Returns the reason if the answer should not be submitted.
+    pub fn check(&self, day: Day, part: u8, answer: &str) -> Result<(), String> {
+        let guesses = self.get(day, part);
+
+        if let Some(correct) = guesses
+            .iter()
+            .find(|guess| guess.verdict == SubmitVerdict::Correct)
+        {
+            return Err(format!(
+                "part {part} was already solved with `{}`.",
+                correct.answer
+            ));
+        }
+
+        if let Some(guess) = guesses.iter().find(|guess| guess.answer == answer) {
+            return Err(format!(
+                "`{answer}` was already submitted and is {}.",
+                guess.verdict
+            ));
+        }
+
+        // NOTE: bounds only apply to numeric answers.
+        let Ok(value) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+
+        let bound = |verdict: SubmitVerdict| {
+            guesses
+                .iter()
+                .filter(move |guess| guess.verdict == verdict)
+                .filter_map(|guess| guess.answer.parse::<i128>().ok())
+        };
+
+        if let Some(upper) = bound(SubmitVerdict::TooHigh).min()
+            && value >= upper
+        {
+            return Err(format!("`{answer}` is too high, `{upper}` was too high."));
+        }
+
+        if let Some(lower) = bound(SubmitVerdict::TooLow).max()
+            && value <= lower
+        {
+            return Err(format!("`{answer}` is too low, `{lower}` was too low."));
+        }
+
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Guesses> for JsonValue {
+    fn from(value: &Guesses) -> Self {
+        let guesses_to_json = |guesses: &[Guess]| {
+            JsonValue::Array(
+                guesses
+                    .iter()
+                    .map(|guess| {
+                        let mut map: HashMap<String, JsonValue> = HashMap::new();
+                        map.insert("answer".into(), JsonValue::String(guess.answer.clone()));
+                        map.insert(
+                            "verdict".into(),
+                            JsonValue::String(guess.verdict.as_str().into()),
+                        );
+                        JsonValue::Object(map)
+                    })
+                    .collect(),
+            )
+        };
+
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        for (day, guesses) in &value.data {
+            let mut day_map: HashMap<String, JsonValue> = HashMap::new();
+            for (key, guesses) in [("part_1", &guesses.part_1), ("part_2", &guesses.part_2)] {
+                if !guesses.is_empty() {
+                    day_map.insert(key.into(), guesses_to_json(guesses));
+                }
+            }
+            map.insert(day.to_string(), JsonValue::Object(day_map));
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl FromStr for Guesses {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let json = JsonValue::from_str(s).or(Err("not valid JSON file."))?;
+
+        let days = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let mut data = HashMap::new();
+
+        for (day, guesses) in days {
+            let day = Day::from_str(day).or(Err(format!("`{day}` is not a valid day.")))?;
+
+            let guesses = guesses
+                .get::<HashMap<String, JsonValue>>()
+                .ok_or(format!("expected guesses of day {day} to be an object."))?;
+
+            let part =
+                |key: &str| -> Result<Vec<Guess>, String> {
+                    let Some(guesses) = guesses.get(key) else {
+                        return Ok(vec![]);
+                    };
+
+                    guesses
+                        .get::<Vec<JsonValue>>()
+                        .ok_or(format!("expected {key} of day {day} to be an array."))?
+                        .iter()
+                        .map(|guess| {
+                            let guess = guess
+                                .get::<HashMap<String, JsonValue>>()
+                                .ok_or(format!("expected guesses of day {day} to be objects."))?;
+
+                            let answer =
+                                guess.get("answer").and_then(|v| v.get::<String>()).ok_or(
+                                    format!("expected guess.answer of day {day} to be a string."),
+                                )?;
+
+                            let verdict = guess
+                                .get("verdict")
+                                .and_then(|v| v.get::<String>())
+                                .ok_or(format!(
+                                    "expected guess.verdict of day {day} to be a string."
+                                ))?
+                                .parse()?;
+
+                            Ok(Guess {
+                                answer: answer.clone(),
+                                verdict,
+                            })
+                        })
+                        .collect()
+                };
+
+            data.insert(
+                day,
+                DayGuesses {
+                    part_1: part("part_1")?,
+                    part_2: part("part_2")?,
+                },
+            );
+        }
+
+        Ok(Guesses { data })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use std::str::FromStr;
+
+    use tinyjson::JsonValue;
+
+    use super::{Guess, Guesses, SubmitVerdict};
+    use crate::day;
+
+    fn guess(answer: &str, verdict: SubmitVerdict) -> Guess {
+        Guess {
+            answer: answer.into(),
+            verdict,
+        }
+    }
+
+    #[test]
+    fn parses_responses() {
+        assert_eq!(
+            SubmitVerdict::from_response("That's the right answer! You are one gold star closer."),
+            Some(SubmitVerdict::Correct)
+        );
+        assert_eq!(
+            SubmitVerdict::from_response("That's not the right answer; your answer is too high."),
+            Some(SubmitVerdict::TooHigh)
+        );
+        assert_eq!(
+            SubmitVerdict::from_response("That's not the right answer; your answer is too low."),
+            Some(SubmitVerdict::TooLow)
+        );
+        assert_eq!(
+            SubmitVerdict::from_response("That's not the right answer. If you're stuck, ..."),
+            Some(SubmitVerdict::Wrong)
+        );
+        assert_eq!(
+            SubmitVerdict::from_response("You gave an answer too recently; you have 30s left."),
+            None
+        );
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let mut guesses = Guesses::default();
+        guesses.record(day!(9), 2, guess("abc", SubmitVerdict::Wrong));
+        assert!(guesses.check(day!(9), 2, "abc").is_err());
+        assert!(guesses.check(day!(9), 2, "abd").is_ok());
+        assert!(guesses.check(day!(9), 1, "abc").is_ok());
+    }
+
+    #[test]
+    fn refuses_answers_out_of_bounds() {
+        let mut guesses = Guesses::default();
+        guesses.record(day!(9), 2, guess("122383625", SubmitVerdict::TooLow));
+        guesses.record(day!(9), 2, guess("2440276020", SubmitVerdict::TooHigh));
+        guesses.record(day!(9), 2, guess("123274025", SubmitVerdict::TooLow));
+
+        assert!(guesses.check(day!(9), 2, "123000000").is_err());
+        assert!(guesses.check(day!(9), 2, "123274025").is_err());
+        assert!(guesses.check(day!(9), 2, "3000000000").is_err());
+        assert!(guesses.check(day!(9), 2, "173149830").is_ok());
+    }
+
+    #[test]
+    fn refuses_solved_parts() {
+        let mut guesses = Guesses::default();
+        guesses.record(day!(1), 1, guess("42", SubmitVerdict::Correct));
+        assert!(guesses.check(day!(1), 1, "43").is_err());
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        let mut guesses = Guesses::default();
+        guesses.record(day!(9), 2, guess("122383625", SubmitVerdict::TooLow));
+        guesses.record(day!(9), 2, guess("173149830", SubmitVerdict::Correct));
+
+        let json = JsonValue::from(&guesses).stringify().unwrap();
+        let parsed = Guesses::from_str(&json).unwrap();
+        assert_eq!(parsed.data, guesses.data);
+    }
+}
@@ -0,0 +1,246 @@
+//! Minimal helpers to work with the HTML pages of the Advent of Code website.
+
+/// Returns the `<article>` elements of a page, which hold the puzzle description or the
+/// response to a submitted answer.
+pub fn extract_articles(html: &str) -> String {
+    articles(html).join("\n\n")
+}
+
+fn articles(html: &str) -> Vec<&str> {
+    let mut articles: Vec<&str> = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let Some(len) = rest[start..].find("</article>") else {
+            break;
+        };
+        let end = start + len + "</article>".len();
+        articles.push(&rest[start..end]);
+        rest = &rest[end..];
+    }
+
+    articles
+}
+
+/// The example of a part of a puzzle description, and the answer the description gives for it.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PartExample {
+    pub input: Option<String>,
+    pub answer: Option<String>,
+}
+
+/// Returns the examples of the parts of a puzzle page, one for every `<article>`.
+///
+/// The example is the largest `<pre><code>` block after the first "For example" of a part.
+/// Its answer is guessed to be the last emphasised code of the part, e.g. `<code><em>42</em></code>`.
+pub fn extract_examples(html: &str) -> Vec<PartExample> {
+    articles(html)
+        .into_iter()
+        .map(|article| PartExample {
+            input: extract_example_input(article),
+            answer: extract_answer(article),
+        })
+        .collect()
+}
+
+fn extract_example_input(article: &str) -> Option<String> {
+    let mut rest = &article[article.find("For example")?..];
+    let mut largest: Option<String> = None;
+
+    while let Some(start) = rest.find("<pre><code>") {
+        rest = &rest[start + "<pre><code>".len()..];
+        let Some(end) = rest.find("</code></pre>") else {
+            break;
+        };
+        let block = decode_entities(&strip_tags(&rest[..end]));
+        rest = &rest[end..];
+
+        if largest
+            .as_ref()
+            .is_none_or(|largest| block.len() > largest.len())
+        {
+            largest = Some(block);
+        }
+    }
+
+    largest.filter(|block| !block.trim().is_empty())
+}
+
+fn extract_answer(article: &str) -> Option<String> {
+    [
+        ("<code><em>", "</em></code>"),
+        ("<em><code>", "</code></em>"),
+    ]
+    .into_iter()
+    .filter_map(|(open, close)| {
+        let start = article.rfind(open)? + open.len();
+        let len = article[start..].find(close)?;
+        Some((start, decode_entities(&article[start..start + len])))
+    })
+    .max_by_key(|(start, _)| *start)
+    .map(|(_, answer)| answer.trim().to_string())
+    .filter(|answer| !answer.is_empty())
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut out = String::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        out.push_str(&rest[..start]);
+        let Some(len) = rest[start..].find('>') else {
+            break;
+        };
+        rest = &rest[start + len + 1..];
+    }
+
+    out.push_str(rest);
+    out
+}
+
+/// Returns the title of a puzzle page, e.g. `Day 1: Secret Entrance` for a heading of
+/// `--- Day 1: Secret Entrance ---`.
+pub fn extract_title(html: &str) -> Option<String> {
+    let start = html.find("<h2")?;
+    let start = start + html[start..].find('>')? + 1;
+    let end = start + html[start..].find("</h2>")?;
+    let title = decode_entities(&html[start..end]);
+    let title = title.trim().trim_matches('-').trim();
+    (!title.is_empty()).then(|| title.to_string())
+}
+
+/// Renders HTML as readable text. Headings, code and emphasis are rendered in markdown syntax.
+pub fn to_text(html: &str) -> String {
+    let mut out = String::new();
+    let mut rest = html;
+    let mut in_pre = false;
+
+    while let Some(start) = rest.find('<') {
+        out.push_str(&decode_entities(&rest[..start]));
+
+        let Some(len) = rest[start..].find('>') else {
+            break;
+        };
+        let tag = &rest[start + 1..start + len];
+        rest = &rest[start + len + 1..];
+
+        let is_closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        match (name.as_str(), is_closing) {
+            ("h2", false) => out.push_str("\n## "),
+            ("h2" | "p" | "ul" | "article", true) => out.push_str("\n\n"),
+            ("li", false) => out.push_str("- "),
+            ("li", true) => out.push('\n'),
+            ("pre", false) => {
+                in_pre = true;
+                out.push_str("```\n");
+            }
+            ("pre", true) => {
+                in_pre = false;
+                out.push_str("```\n\n");
+            }
+            ("code", _) if !in_pre => out.push('`'),
+            ("em", _) => out.push('*'),
+            _ => {}
+        }
+    }
+
+    out.push_str(&decode_entities(rest));
+
+    // NOTE: collapse the blank lines introduced by adjacent block elements.
+    let mut text = out.trim().to_string();
+    while text.contains("\n\n\n") {
+        text = text.replace("\n\n\n", "\n\n");
+    }
+    text
+}
+
+fn decode_entities(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{PartExample, extract_articles, extract_examples, extract_title, to_text};
+
+    #[test]
+    fn extracts_articles() {
+        let html = "<main><article class=\"day-desc\"><p>one</p></article><p>noise</p><article class=\"day-desc\"><p>two</p></article></main>";
+        assert_eq!(
+            extract_articles(html),
+            "<article class=\"day-desc\"><p>one</p></article>\n\n<article class=\"day-desc\"><p>two</p></article>"
+        );
+        assert_eq!(extract_articles("<p>no articles</p>"), "");
+    }
+
+    #[test]
+    fn extracts_titles() {
+        let html = "<article class=\"day-desc\"><h2 id=\"part1\">--- Day 1: Fish &amp; Chips ---</h2><p>one</p></article>";
+        assert_eq!(extract_title(html).as_deref(), Some("Day 1: Fish & Chips"));
+        assert_eq!(extract_title("<p>no title</p>"), None);
+    }
+
+    #[test]
+    fn renders_text() {
+        let html = "<article class=\"day-desc\"><h2>--- Day 1: Test ---</h2><p>For example, <code>a &lt; b</code>:</p><pre><code>1\n2\n</code></pre><ul><li>the answer is <em>3</em>.</li></ul></article>";
+        assert_eq!(
+            to_text(html),
+            "## --- Day 1: Test ---\n\nFor example, `a < b`:\n\n```\n1\n2\n```\n\n- the answer is *3*."
+        );
+    }
+
+    fn part(input: Option<&str>, answer: Option<&str>) -> PartExample {
+        PartExample {
+            input: input.map(String::from),
+            answer: answer.map(String::from),
+        }
+    }
+
+    #[test]
+    fn extracts_the_largest_example() {
+        assert_eq!(
+            extract_examples(include_str!("fixtures/one-part.html")),
+            vec![part(Some("3 4\n4 3\n2 5\n1 3\n3 9\n3 3\n"), Some("11"))]
+        );
+    }
+
+    #[test]
+    fn extracts_examples_of_both_parts() {
+        assert_eq!(
+            extract_examples(include_str!("fixtures/two-parts.html")),
+            vec![
+                part(Some("#.#\n.#.\n<#>\n"), Some("5")),
+                part(None, Some("2"))
+            ]
+        );
+        assert_eq!(
+            extract_examples(include_str!("fixtures/separate-examples.html")),
+            vec![
+                part(Some("a -> b\nb -> c\n"), Some("2")),
+                part(Some("a -> b\nb -> c\nc -> a\n"), Some("6"))
+            ]
+        );
+    }
+
+    #[test]
+    fn extracts_nothing_without_examples() {
+        assert_eq!(
+            extract_examples(include_str!("fixtures/no-example.html")),
+            vec![part(None, None)]
+        );
+    }
+}
@@ -0,0 +1,400 @@
+/// A stand-in for the Advent of Code website, used to test the client without network access.
+///
+/// The server listens on a random port on localhost and serves puzzle pages, inputs and
+/// responses to submitted answers, which mimic the ones of the real website.
+/// [`Sandbox`] points the client at a server and runs commands inside a temporary repository.
+use std::{
+    collections::HashMap,
+    env, fs,
+    io::{self, BufRead, BufReader, Read, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    path::{Path, PathBuf},
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+    },
+    thread::{self, JoinHandle},
+};
+
+use crate::template::aoc_client::redirect_client;
+use crate::template::{Day, PuzzleId, Year, redirect_crate_dir, redirect_data_dir};
+
+/// The session cookie the server accepts.
+pub const SESSION: &str = "mock-session";
+
+/// A submission received by the server.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    pub puzzle: PuzzleId,
+    pub part: u8,
+    pub answer: String,
+}
+
+#[derive(Default)]
+struct State {
+    puzzles: HashMap<PuzzleId, String>,
+    inputs: HashMap<PuzzleId, String>,
+    answers: HashMap<(PuzzleId, u8), String>,
+    stars: HashMap<PuzzleId, u8>,
+    rate_limited: bool,
+    submissions: Vec<Submission>,
+}
+
+/// Configures the content served by a [`MockServer`].
+#[derive(Default)]
+pub struct MockServerBuilder {
+    state: State,
+}
+
+impl MockServerBuilder {
+    /// Serves a puzzle page with the provided `<article>` elements as its description.
+    pub fn puzzle(mut self, puzzle: PuzzleId, description: &str) -> Self {
+        self.state.puzzles.insert(puzzle, description.into());
+        self
+    }
+
+    pub fn input(mut self, puzzle: PuzzleId, input: &str) -> Self {
+        self.state.inputs.insert(puzzle, input.into());
+        self
+    }
+
+    /// Sets the correct answer of a part, submissions are judged against it.
+    pub fn answer(mut self, puzzle: PuzzleId, part: u8, answer: &str) -> Self {
+        self.state.answers.insert((puzzle, part), answer.into());
+        self
+    }
+
+    /// Shows the stars of a day on the calendar page. Correct submissions add stars as well.
+    pub fn stars(mut self, puzzle: PuzzleId, stars: u8) -> Self {
+        self.state.stars.insert(puzzle, stars);
+        self
+    }
+
+    /// Responds to every submission as if an answer was submitted too recently.
+    pub fn rate_limited(mut self) -> Self {
+        self.state.rate_limited = true;
+        self
+    }
+
+    pub fn start(self) -> MockServer {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("could not bind mock server");
+        let addr = listener.local_addr().unwrap();
+        let state = Arc::new(Mutex::new(self.state));
+        let stopped = Arc::new(AtomicBool::new(false));
+
+        let handle = thread::spawn({
+            let state = Arc::clone(&state);
+            let stopped = Arc::clone(&stopped);
+            move || {
+                for stream in listener.incoming() {
+                    if stopped.load(Ordering::SeqCst) {
+                        break;
+                    }
+                    if let Ok(stream) = stream {
+                        // NOTE: a failing request must not take down the server.
+                        let _ = handle_connection(stream, &state);
+                    }
+                }
+            }
+        });
+
+        MockServer {
+            addr,
+            state,
+            stopped,
+            handle: Some(handle),
+        }
+    }
+}
+
+/// A running stand-in server, which is stopped when dropped.
+pub struct MockServer {
+    addr: SocketAddr,
+    state: Arc<Mutex<State>>,
+    stopped: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl MockServer {
+    pub fn builder() -> MockServerBuilder {
+        MockServerBuilder::default()
+    }
+
+    pub fn base_url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// Returns all submissions received so far.
+    pub fn submissions(&self) -> Vec<Submission> {
+        self.state.lock().unwrap().submissions.clone()
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::SeqCst);
+        // wake up the listener, so that it notices that the server was stopped.
+        let _ = TcpStream::connect(self.addr);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+struct Request {
+    method: String,
+    path: String,
+    cookie: Option<String>,
+    body: String,
+}
+
+fn read_request(stream: &TcpStream) -> io::Result<Request> {
+    let mut reader = BufReader::new(stream);
+
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    let mut parts = line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let path = parts.next().unwrap_or_default().to_string();
+
+    let mut cookie = None;
+    let mut content_length = 0;
+
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            match name.trim().to_ascii_lowercase().as_str() {
+                "cookie" => cookie = Some(value.trim().to_string()),
+                "content-length" => content_length = value.trim().parse().unwrap_or(0),
+                _ => {}
+            }
+        }
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+
+    Ok(Request {
+        method,
+        path,
+        cookie,
+        body: String::from_utf8_lossy(&body).to_string(),
+    })
+}
+
+fn handle_connection(mut stream: TcpStream, state: &Mutex<State>) -> io::Result<()> {
+    let request = read_request(&stream)?;
+    let (status, body) = route(&request, &mut state.lock().unwrap());
+
+    write!(
+        stream,
+        "HTTP/1.1 {status}\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )?;
+    stream.flush()
+}
+
+fn route(request: &Request, state: &mut State) -> (&'static str, String) {
+    if request.cookie.as_deref() != Some(&format!("session={SESSION}")) {
+        return (
+            "400 Bad Request",
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n".into(),
+        );
+    }
+
+    let segments: Vec<&str> = request.path.trim_matches('/').split('/').collect();
+
+    if let ("GET", [year]) = (request.method.as_str(), segments.as_slice()) {
+        return match year.parse().ok().and_then(Year::new) {
+            Some(year) => ("200 OK", calendar(year, state)),
+            None => ("404 Not Found", "404 Not Found".into()),
+        };
+    }
+
+    let puzzle = match segments.as_slice() {
+        [year, "day", day, ..] => parse_puzzle(year, day),
+        _ => None,
+    };
+    let Some(puzzle) = puzzle else {
+        return ("404 Not Found", "404 Not Found".into());
+    };
+
+    match (request.method.as_str(), &segments[3..]) {
+        ("GET", []) => match state.puzzles.get(&puzzle) {
+            Some(description) => ("200 OK", page(description)),
+            None => ("404 Not Found", "404 Not Found".into()),
+        },
+        ("GET", ["input"]) => match state.inputs.get(&puzzle) {
+            Some(input) => ("200 OK", input.clone()),
+            None => ("404 Not Found", "404 Not Found".into()),
+        },
+        ("POST", ["answer"]) => ("200 OK", page(&submit(puzzle, &request.body, state))),
+        _ => ("404 Not Found", "404 Not Found".into()),
+    }
+}
+
+fn parse_puzzle(year: &str, day: &str) -> Option<PuzzleId> {
+    let year = Year::new(year.parse().ok()?)?;
+    let day = Day::new(day.parse().ok()?)?;
+    PuzzleId::new(year, day)
+}
+
+fn page(article: &str) -> String {
+    format!("<!DOCTYPE html>\n<html><body><main>\n{article}\n</main></body></html>\n")
+}
+
+fn calendar(year: Year, state: &State) -> String {
+    let days: Vec<String> = year
+        .days()
+        .map(|day| {
+            let n = day.into_inner();
+            let stars = PuzzleId::new(year, day)
+                .and_then(|puzzle| state.stars.get(&puzzle))
+                .copied()
+                .unwrap_or_default();
+            let (label, class) = match stars {
+                0 => ("", ""),
+                1 => (", one star", " calendar-complete"),
+                _ => (", two stars", " calendar-verycomplete"),
+            };
+            format!(
+                "<a aria-label=\"Day {n}{label}\" href=\"/{year}/day/{n}\" class=\"calendar-day{n}{class}\">{n}</a>"
+            )
+        })
+        .collect();
+
+    format!(
+        "<!DOCTYPE html>\n<html><body><main>\n<pre class=\"calendar\">{}</pre>\n</main></body></html>\n",
+        days.join("\n")
+    )
+}
+
+fn submit(puzzle: PuzzleId, form: &str, state: &mut State) -> String {
+    let field = |name: &str| {
+        form.split('&')
+            .filter_map(|pair| pair.split_once('='))
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| value.to_string())
+            .unwrap_or_default()
+    };
+
+    let part: u8 = field("level").parse().unwrap_or_default();
+    let answer = field("answer");
+    let day = puzzle.day.into_inner();
+    let back = format!(
+        "<a href=\"/{}/day/{day}\">[Return to Day {day}]</a>",
+        puzzle.year
+    );
+
+    if state.rate_limited {
+        return format!(
+            "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 41s left to wait. {back}</p></article>"
+        );
+    }
+
+    state.submissions.push(Submission {
+        puzzle,
+        part,
+        answer: answer.clone(),
+    });
+
+    let expected = state
+        .answers
+        .get(&(puzzle, part))
+        .cloned()
+        .unwrap_or_default();
+
+    if answer == expected {
+        let stars = state.stars.entry(puzzle).or_default();
+        *stars = (*stars).max(part);
+        return format!(
+            "<article><p>That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to finishing. {back}</p></article>"
+        );
+    }
+
+    let hint = match (answer.parse::<i128>(), expected.parse::<i128>()) {
+        (Ok(answer), Ok(expected)) if answer > expected => "  your answer is too high.",
+        (Ok(answer), Ok(expected)) if answer < expected => "  your answer is too low.",
+        _ => "",
+    };
+
+    format!(
+        "<article><p>That's not the right answer;{hint}  If you're stuck, make sure you're using the full input data.  Please wait one minute before trying again. {back}</p></article>"
+    )
+}
+
+/* -------------------------------------------------------------------------- */
+
+static SANDBOX_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// A temporary repository with a `data` directory and a `rust` directory. While the sandbox is
+/// alive, the crate and data directory of the current thread are redirected to it and its clients
+/// to the provided server.
+///
+/// The process environment and working directory are left untouched, so tests with sandboxes run
+/// in parallel with each other and with tests that read the environment. A thread can only have
+/// one sandbox at a time.
+pub struct Sandbox {
+    root: PathBuf,
+}
+
+impl Sandbox {
+    pub fn new(server: &MockServer) -> Self {
+        let root = env::temp_dir().join(format!(
+            "aoc-sandbox-{}-{}",
+            std::process::id(),
+            SANDBOX_COUNT.fetch_add(1, Ordering::SeqCst)
+        ));
+        fs::create_dir_all(root.join("rust").join("src").join("bin")).unwrap();
+        fs::create_dir_all(root.join("data")).unwrap();
+        copy_templates(&root.join("rust").join("templates"));
+
+        redirect_crate_dir(Some(root.join("rust")));
+        redirect_data_dir(Some(root.join("data")));
+        redirect_client(Some((&server.base_url(), SESSION)));
+
+        Self { root }
+    }
+
+    /// Returns the path of a file in the sandbox, relative to its root.
+    pub fn path(&self, path: &str) -> PathBuf {
+        self.root.join(path)
+    }
+
+    /// Reads a file in the sandbox, relative to its root.
+    pub fn read(&self, path: &str) -> String {
+        fs::read_to_string(self.path(path)).unwrap_or_default()
+    }
+
+    pub fn exists(&self, path: &str) -> bool {
+        Path::new(&self.path(path)).exists()
+    }
+}
+
+/// Copies the templates of the crate into a sandbox, so that they can be scaffolded.
+fn copy_templates(target: &Path) {
+    let templates = Path::new(env!("CARGO_MANIFEST_DIR")).join("templates");
+    for template in fs::read_dir(templates).unwrap().flatten() {
+        let dir = target.join(template.file_name());
+        fs::create_dir_all(&dir).unwrap();
+        for file in fs::read_dir(template.path()).unwrap().flatten() {
+            fs::copy(file.path(), dir.join(file.file_name())).unwrap();
+        }
+    }
+}
+
+impl Drop for Sandbox {
+    fn drop(&mut self) {
+        redirect_crate_dir(None);
+        redirect_data_dir(None);
+        redirect_client(None);
+        let _ = fs::remove_dir_all(&self.root);
+    }
+}
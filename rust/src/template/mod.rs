@@ -1,63 +1,292 @@
-use std::{env, fs};
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
 
-pub mod aoc_cli;
+use crate::template::examples::Example;
+use crate::template::front_matter::strip_front_matter;
+use crate::template::params::SolutionParams;
+
+pub mod answers;
+pub mod aoc_client;
 pub mod commands;
+pub mod examples;
+pub mod front_matter;
+pub mod guesses;
+#[cfg(feature = "test_lib")]
+pub mod mock_server;
+pub mod params;
+pub mod part_report;
+pub mod registry;
+pub mod report;
 pub mod runner;
+pub mod stars;
+pub mod stats;
 
+pub use data::*;
 pub use day::*;
+pub use puzzle::*;
+pub use year::*;
 
+mod data;
 mod day;
+mod git;
+mod html;
+mod output;
+mod puzzle;
 mod readme_benchmarks;
+mod readme_stars;
 mod run_multi;
 mod timings;
+mod year;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+/// Returns the path of a file in the data directory of a year, e.g. `data/2025/answers.json`.
+/// See [`data_dir`] for how the data directory is found.
+pub fn data_path(year: Year, path: impl AsRef<Path>) -> PathBuf {
+    data_dir().join(year.to_string()).join(path)
+}
+
+fn get_data_path(folder: &str, puzzle: PuzzleId, name: &str) -> PathBuf {
+    data_path(puzzle.year, folder).join(format!("{name}.txt"))
+}
+
 /// Helper function that reads a text file to a string.
+/// A front matter at the start of the file is skipped, see [`params`].
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd
-        .join("../")
-        .join("data")
-        .join(folder)
-        .join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+pub fn read_file(folder: &str, puzzle: PuzzleId) -> String {
+    try_read_file(folder, puzzle).expect("could not open input file")
+}
+
+/// Helper function that reads a text file to a string, returning an error if it can't be read.
+pub fn try_read_file(folder: &str, puzzle: PuzzleId) -> io::Result<String> {
+    let contents = fs::read_to_string(get_data_path(folder, puzzle, &puzzle.day.to_string()))?;
+    Ok(strip_front_matter(&contents).to_string())
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd
-        .join("data")
-        .join(folder)
-        .join(format!("{day}-{part}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+pub fn read_file_part(folder: &str, puzzle: PuzzleId, part: u8) -> String {
+    let name = format!("{}-{part}", puzzle.day);
+    let f = fs::read_to_string(get_data_path(folder, puzzle, &name));
+    strip_front_matter(&f.expect("could not open input file")).to_string()
+}
+
+/// Helper function that reads an example, returning its input and the parameters of the solution
+/// with the overrides from the example's front matter applied.
+#[must_use]
+pub fn read_example<P: SolutionParams>(puzzle: PuzzleId) -> (String, P) {
+    read_example_file(puzzle, &puzzle.day.to_string())
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Like [`read_example`], but reads the example of a part. E.g. like `01-2.txt`.
+#[must_use]
+pub fn read_example_part<P: SolutionParams>(puzzle: PuzzleId, part: u8) -> (String, P) {
+    read_example_file(puzzle, &format!("{}-{part}", puzzle.day))
+}
+
+fn read_example_file<P: SolutionParams>(puzzle: PuzzleId, name: &str) -> (String, P) {
+    let path = get_data_path("examples", puzzle, name);
+    let contents = fs::read_to_string(&path).expect("could not open example file");
+    let (params, input) = Example::parse(&contents)
+        .and_then(|example| Ok((P::from_front_matter(&example.params)?, example.input)))
+        .unwrap_or_else(|e| panic!("invalid front matter in {}: {e}", path.display()));
+    (input.to_string(), params)
+}
+
+/// Creates the constant `PUZZLE` and sets up the input and runner for each part.
+/// Also defines the constant `SOLUTION`, which registers the parts for in-process runs.
 ///
 /// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// Further options are passed as `key = value` pairs, in any order:
+///  - `year = <year>` sets the year of the puzzle, it defaults to `AOC_YEAR`.
+///  - `parse = <fn>` sets a function `fn(&str) -> T` that parses the input once. Both parts then
+///    receive `&T` instead of `&str`, and the parse step is timed separately from the parts.
+///  - `timeout = <duration>` sets the timeout of the parts, unless one is passed via `--timeout`.
+///  - `params = <type>` sets the parameters of the solution, declared with [`params!`]. Both parts
+///    then receive `&<type>` as a second argument, which holds the defaults for the real input.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+    ($day:expr, 1 $(, $($opts:tt)*)?) => {
+        $crate::solution!(@opts $day, [[part_one, 1]], [], [], [], []; $($($opts)*)?);
+    };
+    ($day:expr, 2 $(, $($opts:tt)*)?) => {
+        $crate::solution!(@opts $day, [[part_two, 2]], [], [], [], []; $($($opts)*)?);
+    };
+    ($day:expr $(, $($opts:tt)*)?) => {
+        $crate::solution!(@opts $day, [[part_one, 1] [part_two, 2]], [], [], [], []; $($($opts)*)?);
+    };
+
+    (@opts $day:expr, $parts:tt, $year:tt, $parse:tt, $timeout:tt, $params:tt; year = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::solution!(@opts $day, $parts, [$value], $parse, $timeout, $params; $($($rest)*)?);
+    };
+    (@opts $day:expr, $parts:tt, $year:tt, $parse:tt, $timeout:tt, $params:tt; parse = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::solution!(@opts $day, $parts, $year, [$value], $timeout, $params; $($($rest)*)?);
+    };
+    (@opts $day:expr, $parts:tt, $year:tt, $parse:tt, $timeout:tt, $params:tt; timeout = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::solution!(@opts $day, $parts, $year, $parse, [$value], $params; $($($rest)*)?);
+    };
+    (@opts $day:expr, $parts:tt, $year:tt, $parse:tt, $timeout:tt, $params:tt; params = $value:ty $(, $($rest:tt)*)?) => {
+        $crate::solution!(@opts $day, $parts, $year, $parse, $timeout, [$value]; $($($rest)*)?);
+    };
+    (@opts $day:expr, $parts:tt, [$($year:expr)?], $parse:tt, [$($timeout:expr)?], $params:tt;) => {
+        $crate::solution!(@impl $crate::solution!(@year $($year)?), $day, $parts, $parse, [$($timeout)?], $params);
+    };
+
+    (@year $year:expr) => {
+        $crate::year!($year)
+    };
+    (@year) => {
+        const {
+            $crate::template::Year::COMPILED
+                .expect("`AOC_YEAR` is not set, pass the year via `solution!(<day>, year = <year>)`")
+        }
+    };
+
+    (@timeout $timeout:expr) => {
+        Some($timeout)
+    };
+    (@timeout) => {
+        None
+    };
+
+    (@params $params:ty) => {
+        <$params as ::core::default::Default>::default()
+    };
+    (@params) => {
+        ()
+    };
+
+    (@params_type $params:ty) => {
+        $params
     };
-    ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+    (@params_type) => {
+        ()
     };
-    ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+
+    (@solve_example $input:ident, $params:ident, $part:ident, $ty:tt, [$( [$func:expr, $num:expr] )*]) => {
+        match $part {
+            $( $num => $crate::solution!(@call $func, $input, $ty, $params).outcome(), )*
+            _ => panic!("the solution has no part {}", $part),
+        }
+    };
+
+    (@part $func:expr, [], $params:ident) => {
+        $func
+    };
+    (@part $func:expr, [$ty:ty], $params:ident) => {
+        move |input| $func(input, $params)
+    };
+
+    (@example $example:ident, [], $params:ident) => {
+        $example.input
+    };
+    (@example $example:ident, [$parse:expr], $params:ident) => {
+        &$parse($example.input)
+    };
+
+    (@call $func:expr, $input:ident, [], $params:ident) => {
+        $func($input)
+    };
+    (@call $func:expr, $input:ident, [$ty:ty], $params:ident) => {
+        $func($input, &$params)
+    };
+
+    (@keep borrow, $value:ident) => {
+        &$value
+    };
+    (@keep leak, $value:ident) => {
+        $crate::template::runner::leak_parsed($value)
     };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
-        /// The current day.
-        const DAY: $crate::template::Day = $crate::day!($day);
+    // NOTE: the input, parsed input and parameters are only leaked with a timeout, as parts that
+    // time out keep running in the background and hold on to them.
+    (@run $input:ident, $options:ident, $params:ident, $parse:tt, $ty:tt, $parts:tt, $emit:expr) => {
+        match $options.timeout {
+            None => $crate::solution!(
+                @steps borrow, run_parse_with, run_part_with,
+                $input, $options, $params, $parse, $ty, $parts, $emit
+            ),
+            Some(_) => {
+                let $input = leak_input($input);
+                $crate::solution!(
+                    @steps leak, run_parse_with_timeout, run_part_with_timeout,
+                    $input, $options, $params, $parse, $ty, $parts, $emit
+                )
+            }
+        }
+    };
+
+    (@steps $keep:ident, $run_parse:ident, $run_part:ident, $input:ident, $options:ident, $params:ident, [], $ty:tt, [$( [$func:expr, $part:expr] )*], $emit:expr) => {{
+        #[allow(unused_variables)]
+        let $params = $crate::solution!(@keep $keep, $params);
+        vec![$({
+            let report = $run_part($crate::solution!(@part $func, $ty, $params), $input, PUZZLE, $part, $options);
+            $emit(&report);
+            report
+        }),*]
+    }};
+    (@steps $keep:ident, $run_parse:ident, $run_part:ident, $input:ident, $options:ident, $params:ident, [$parse:expr], $ty:tt, [$( [$func:expr, $part:expr] )*], $emit:expr) => {{
+        #[allow(unused_variables)]
+        let $params = $crate::solution!(@keep $keep, $params);
+        let (parsed, report) = $run_parse($parse, $input, $options);
+        $emit(&report);
+        let mut reports = vec![report];
+        // NOTE: the parts can not run if the parse step failed.
+        if let Some(parsed) = parsed {
+            let parsed = $crate::solution!(@keep $keep, parsed);
+            $({
+                let report = $run_part($crate::solution!(@part $func, $ty, $params), parsed, PUZZLE, $part, $options);
+                $emit(&report);
+                reports.push(report);
+            })*
+        }
+        reports
+    }};
+
+    (@impl $year:expr, $day:expr, $parts:tt, $parse:tt, [$($timeout:expr)?], [$($params:ty)?]) => {
+        /// The current puzzle.
+        const PUZZLE: $crate::template::PuzzleId = const {
+            $crate::template::PuzzleId::new($year, $crate::day!($day))
+                .expect("invalid day number, the day does not exist in this year")
+        };
+
+        /// The timeout of the solution's parts, unless a timeout is passed via `--timeout`.
+        #[allow(dead_code)]
+        const TIMEOUT: Option<::std::time::Duration> = $crate::solution!(@timeout $($timeout)?);
+
+        /// The solution's parts, erased so they can be run by the `aoc-runner` binary.
+        #[allow(dead_code)]
+        pub const SOLUTION: $crate::template::registry::Solution =
+            $crate::template::registry::Solution {
+                puzzle: PUZZLE,
+                run: |input, options| {
+                    use $crate::template::runner::*;
+                    let options = options.with_default_timeout(TIMEOUT);
+                    #[allow(clippy::let_unit_value)]
+                    let params = $crate::solution!(@params $($params)?);
+                    $crate::solution!(@run input, options, params, $parse, [$($params)?], $parts, |_: &$crate::template::part_report::PartReport| {})
+                },
+            };
+
+        /// Solves a part of an example with the parameters of its front matter, see [`example_tests!`].
+        #[cfg(test)]
+        #[allow(dead_code)]
+        fn solve_example(
+            example: &$crate::template::examples::Example,
+            part: u8,
+        ) -> $crate::template::runner::PartOutcome {
+            use $crate::template::params::SolutionParams;
+            use $crate::template::runner::PartOutput;
+            #[allow(clippy::let_unit_value, unused_variables)]
+            let params = <$crate::solution!(@params_type $($params)?)>::from_front_matter(&example.params)
+                .expect("invalid parameters in example");
+            let input = $crate::solution!(@example example, $parse, params);
+            $crate::solution!(@solve_example input, params, part, [$($params)?], $parts)
+        }
 
         #[cfg(feature = "dhat-heap")]
         #[global_allocator]
@@ -65,8 +294,63 @@ macro_rules! solution {
 
         fn main() {
             use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            let input = $crate::template::read_file("inputs", PUZZLE);
+            let input = input.as_str();
+            let options = RunOptions::from_args().with_default_timeout(TIMEOUT);
+            #[allow(clippy::let_unit_value)]
+            let params = $crate::solution!(@params $($params)?);
+            let emit = |report: &$crate::template::part_report::PartReport| emit_report(report, PUZZLE);
+            $crate::solution!(@run input, options, params, $parse, [$($params)?], $parts, emit);
         }
     };
 }
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use std::{env, fs, path::Path};
+
+    use super::{
+        DATA_DIR_ENV, PuzzleId, crate_dir, data_dir, data_path, read_file, read_file_part,
+    };
+    use crate::template::mock_server::{MockServer, Sandbox};
+    use crate::{day, year};
+
+    #[test]
+    fn resolves_data_dir() {
+        let sandbox = Sandbox::new(&MockServer::builder().start());
+        assert_eq!(data_dir(), sandbox.path("data"));
+        assert_eq!(
+            data_path(year!(2025), "answers.json"),
+            sandbox.path("data/2025/answers.json")
+        );
+
+        assert_eq!(crate_dir(), sandbox.path("rust"));
+
+        drop(sandbox);
+        assert_eq!(crate_dir(), Path::new(env!("CARGO_MANIFEST_DIR")));
+
+        // NOTE: the data directory can still be overridden for the whole test run.
+        if env::var_os(DATA_DIR_ENV).is_none() {
+            let crate_data_dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+                .parent()
+                .unwrap()
+                .join("data");
+            assert_eq!(data_dir(), crate_data_dir);
+        }
+    }
+
+    #[test]
+    fn reads_files_without_front_matter() {
+        let sandbox = Sandbox::new(&MockServer::builder().start());
+        let examples = sandbox.path("data/2025/examples");
+        fs::create_dir_all(&examples).unwrap();
+        fs::write(examples.join("01.txt"), "---\npart_1: 3\n---\n1\n2\n").unwrap();
+        fs::write(examples.join("01-2.txt"), "3\n4\n").unwrap();
+
+        let puzzle = PuzzleId::new(year!(2025), day!(1)).unwrap();
+        assert_eq!(read_file("examples", puzzle), "1\n2\n");
+        assert_eq!(read_file_part("examples", puzzle, 2), "3\n4\n");
+    }
+}
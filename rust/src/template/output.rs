@@ -0,0 +1,82 @@
+/// Output of solution runs, which can be captured per thread.
+///
+/// When days run in parallel, every day runs on its own thread with its output captured,
+/// so that the output can be printed in day order once the day is done.
+use std::cell::RefCell;
+use std::fmt;
+
+thread_local! {
+    static CAPTURED: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Run a function, capturing everything it prints via [`out!`] and [`outln!`] on this thread.
+pub fn capture<R>(func: impl FnOnce() -> R) -> (R, String) {
+    let previous = CAPTURED.replace(Some(String::new()));
+    let result = func();
+    let output = CAPTURED.replace(previous).unwrap_or_default();
+    (result, output)
+}
+
+/// Whether the output of the current thread is captured.
+pub fn is_captured() -> bool {
+    CAPTURED.with_borrow(Option::is_some)
+}
+
+/// Write to the captured output of the current thread, or to stdout if it is not captured.
+pub fn write(args: fmt::Arguments) {
+    CAPTURED.with_borrow_mut(|captured| match captured {
+        Some(output) => fmt::Write::write_fmt(output, args).unwrap(),
+        None => print!("{args}"),
+    });
+}
+
+/// Like `print!`, but respects captured output.
+macro_rules! out {
+    ($($arg:tt)*) => {
+        $crate::template::output::write(format_args!($($arg)*))
+    };
+}
+
+/// Like `println!`, but respects captured output.
+macro_rules! outln {
+    () => {
+        $crate::template::output::write(format_args!("\n"))
+    };
+    ($($arg:tt)*) => {{
+        $crate::template::output::write(format_args!($($arg)*));
+        $crate::template::output::write(format_args!("\n"));
+    }};
+}
+
+pub(crate) use {out, outln};
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{capture, is_captured};
+
+    #[test]
+    fn captures_output() {
+        let (result, output) = capture(|| {
+            out!("Part {}:", 1);
+            outln!(" {}", 42);
+            outln!();
+            is_captured()
+        });
+        assert!(result);
+        assert_eq!(output, "Part 1: 42\n\n");
+        assert!(!is_captured());
+    }
+
+    #[test]
+    fn restores_outer_capture() {
+        let (_, outer) = capture(|| {
+            out!("a");
+            let (_, inner) = capture(|| out!("b"));
+            assert_eq!(inner, "b");
+            out!("c");
+        });
+        assert_eq!(outer, "ac");
+    }
+}
@@ -0,0 +1,121 @@
+//! Typed parameters of solutions, so examples and real inputs can use different constants.
+//!
+//! A solution declares its parameters with [`params!`](crate::params), where every parameter has
+//! the default used for the real input. Example files override them in a front matter, a block
+//! of `key: value` lines between two `---` lines at the start of the file:
+//!
+//! ```text
+//! ---
+//! connections: 10
+//! ---
+//! 162,817,812
+//! ```
+
+/// Parameters of a solution. The default holds the values for the real input.
+pub trait SolutionParams: Default {
+    /// Override a parameter with a value from the front matter of an example.
+    fn set(&mut self, key: &str, value: &str) -> Result<(), String>;
+
+    /// Returns the defaults with the overrides of a front matter applied.
+    fn from_front_matter(entries: &[(&str, &str)]) -> Result<Self, String> {
+        let mut params = Self::default();
+        for (key, value) in entries {
+            params.set(key, value)?;
+        }
+        Ok(params)
+    }
+}
+
+/// Solutions without parameters.
+impl SolutionParams for () {
+    fn set(&mut self, key: &str, _value: &str) -> Result<(), String> {
+        Err(format!(
+            "unknown parameter `{key}`, the solution has no parameters."
+        ))
+    }
+}
+
+/// Declares the parameters of a solution as a struct, with the default of every field being its
+/// value for the real input. Fields are overridden by the front matter of examples, parsed via
+/// [`FromStr`](std::str::FromStr).
+///
+/// ```
+/// advent_of_code::params! {
+///     pub struct Params {
+///         /// The number of pairs to connect.
+///         pub connections: usize = 1000,
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! params {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $( $(#[$field_meta:meta])* $field_vis:vis $field:ident : $ty:ty = $default:expr ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug, PartialEq)]
+        $vis struct $name {
+            $( $(#[$field_meta])* $field_vis $field: $ty ),*
+        }
+
+        impl ::core::default::Default for $name {
+            fn default() -> Self {
+                Self { $( $field: $default ),* }
+            }
+        }
+
+        impl $crate::template::params::SolutionParams for $name {
+            fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+                match key {
+                    $(
+                        stringify!($field) => {
+                            self.$field = value.parse::<$ty>().map_err(|e| {
+                                format!("invalid value `{value}` for parameter `{key}`: {e}")
+                            })?;
+                            Ok(())
+                        }
+                    )*
+                    _ => Err(format!("unknown parameter `{key}`.")),
+                }
+            }
+        }
+    };
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::SolutionParams;
+
+    crate::params! {
+        struct Params {
+            connections: usize = 1000,
+            skip: bool = true,
+        }
+    }
+
+    #[test]
+    fn applies_overrides() {
+        assert_eq!(
+            Params::from_front_matter(&[]).unwrap(),
+            Params {
+                connections: 1000,
+                skip: true
+            }
+        );
+        assert_eq!(
+            Params::from_front_matter(&[("connections", "10")]).unwrap(),
+            Params {
+                connections: 10,
+                skip: true
+            }
+        );
+        assert!(Params::from_front_matter(&[("connections", "ten")]).is_err());
+        assert!(Params::from_front_matter(&[("amount", "10")]).is_err());
+        assert!(<()>::from_front_matter(&[("amount", "10")]).is_err());
+    }
+}
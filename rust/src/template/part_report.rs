@@ -0,0 +1,324 @@
+/// Machine-readable results of solution parts.
+///
+/// When the `AOC_REPORT_FILE` environment variable is set, solution binaries append one JSON
+/// record per part to that file. This allows runners to consume results without parsing the
+/// human-readable output.
+use std::{
+    collections::HashMap,
+    env,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    str::FromStr,
+    time::Duration,
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::stats::BenchStats;
+
+pub const REPORT_FILE_ENV: &str = "AOC_REPORT_FILE";
+
+/// The part number used for reports of the `parse` step of a solution.
+pub const PARSE_PART: u8 = 0;
+
+/// The outcome of a solution part.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PartStatus {
+    /// The part returned an answer.
+    Solved,
+    /// The part returned no answer.
+    Unsolved,
+    /// The part returned an error or panicked.
+    Failed,
+    /// The part did not return before its timeout.
+    TimedOut,
+}
+
+impl PartStatus {
+    fn as_str(self) -> &'static str {
+        match self {
+            PartStatus::Solved => "solved",
+            PartStatus::Unsolved => "unsolved",
+            PartStatus::Failed => "failed",
+            PartStatus::TimedOut => "timed_out",
+        }
+    }
+}
+
+impl FromStr for PartStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "solved" => Ok(PartStatus::Solved),
+            "unsolved" => Ok(PartStatus::Unsolved),
+            "failed" => Ok(PartStatus::Failed),
+            "timed_out" => Ok(PartStatus::TimedOut),
+            s => Err(format!("Unknown part status `{s}`.")),
+        }
+    }
+}
+
+/// The outcome of running a single solution part.
+#[derive(Clone, Debug)]
+pub struct PartReport {
+    /// The part number, or [`PARSE_PART`] for the `parse` step.
+    pub part: u8,
+    pub status: PartStatus,
+    pub answer: Option<String>,
+    /// The error message, if the part failed or timed out.
+    pub error: Option<String>,
+    pub duration: Duration,
+    pub samples: u128,
+    /// Statistics over the samples, if the part was benched.
+    pub stats: Option<BenchStats>,
+    /// Peak heap usage of the first run in bytes, if the solution was built with `dhat-heap`.
+    pub peak_bytes: Option<u64>,
+}
+
+impl PartReport {
+    /// Append the report to the file referenced by `AOC_REPORT_FILE`, if set.
+    pub fn emit(&self) -> io::Result<()> {
+        let Some(path) = env::var_os(REPORT_FILE_ENV) else {
+            return Ok(());
+        };
+
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        let line = JsonValue::from(self)
+            .stringify()
+            .map_err(|e| io::Error::other(e.to_string()))?;
+        writeln!(file, "{line}")
+    }
+}
+
+/// Read all reports from a report file. Lines that are not valid reports are returned as errors.
+pub fn read_reports(path: &Path) -> io::Result<Vec<Result<PartReport, String>>> {
+    let contents = fs::read_to_string(path)?;
+    Ok(parse_reports(&contents))
+}
+
+fn parse_reports(contents: &str) -> Vec<Result<PartReport, String>> {
+    contents
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let json = JsonValue::from_str(line).or(Err("not valid JSON."))?;
+            PartReport::try_from(&json)
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&PartReport> for JsonValue {
+    fn from(value: &PartReport) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert(
+            "status".into(),
+            JsonValue::String(value.status.as_str().into()),
+        );
+        map.insert(
+            "answer".into(),
+            match &value.answer {
+                Some(answer) => JsonValue::String(answer.clone()),
+                None => JsonValue::Null,
+            },
+        );
+        if let Some(error) = &value.error {
+            map.insert("error".into(), JsonValue::String(error.clone()));
+        }
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "nanos".into(),
+            JsonValue::Number(value.duration.as_nanos() as f64),
+        );
+        #[allow(clippy::cast_precision_loss)]
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+        map.insert(
+            "stats".into(),
+            match &value.stats {
+                Some(stats) => JsonValue::from(stats),
+                None => JsonValue::Null,
+            },
+        );
+        if let Some(peak_bytes) = value.peak_bytes {
+            #[allow(clippy::cast_precision_loss)]
+            map.insert("peak_bytes".into(), JsonValue::Number(peak_bytes as f64));
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartReport {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected report to be a JSON object.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|part| *part as u8)
+            .ok_or("Expected report.part to be a number.")?;
+
+        let status = json
+            .get("status")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected report.status to be a string.")?
+            .parse()?;
+
+        let answer = json
+            .get("answer")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected report.answer to be null or string.")?;
+
+        // NOTE: the error is optional, it is only written for failed parts.
+        let error = json
+            .get("error")
+            .map(|v| {
+                v.get::<String>()
+                    .ok_or("Expected report.error to be a string.")
+            })
+            .transpose()?;
+
+        let nanos = json
+            .get("nanos")
+            .and_then(|v| v.get::<f64>())
+            .ok_or("Expected report.nanos to be a number.")?;
+
+        let samples = json
+            .get("samples")
+            .and_then(|v| v.get::<f64>())
+            .ok_or("Expected report.samples to be a number.")?;
+
+        let stats = match json.get("stats") {
+            Some(v) if !v.is_null() => Some(BenchStats::try_from(v)?),
+            _ => None,
+        };
+
+        // NOTE: the peak heap usage is optional, it is only measured with `dhat-heap`.
+        let peak_bytes = json
+            .get("peak_bytes")
+            .map(|v| {
+                v.get::<f64>()
+                    .map(|bytes| *bytes as u64)
+                    .ok_or("Expected report.peak_bytes to be a number.")
+            })
+            .transpose()?;
+
+        Ok(PartReport {
+            part,
+            status,
+            answer: answer.cloned(),
+            error: error.cloned(),
+            duration: Duration::from_nanos(*nanos as u64),
+            samples: *samples as u128,
+            stats,
+            peak_bytes,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use std::time::Duration;
+
+    use tinyjson::JsonValue;
+
+    use super::{PartReport, PartStatus, parse_reports};
+    use crate::template::stats::BenchStats;
+
+    #[test]
+    fn round_trips_reports() {
+        let report = PartReport {
+            part: 2,
+            status: PartStatus::Solved,
+            answer: Some("42".into()),
+            error: None,
+            duration: Duration::from_nanos(74_130),
+            samples: 10_000,
+            stats: BenchStats::from_samples(&[Duration::from_nanos(74_130); 4]),
+            peak_bytes: Some(2_048),
+        };
+
+        let line = JsonValue::from(&report).stringify().unwrap();
+        let parsed = parse_reports(&line);
+        let parsed = parsed[0].as_ref().unwrap();
+
+        assert_eq!(parsed.part, 2);
+        assert_eq!(parsed.status, PartStatus::Solved);
+        assert_eq!(parsed.answer.as_deref(), Some("42"));
+        assert_eq!(parsed.duration, Duration::from_nanos(74_130));
+        assert_eq!(parsed.samples, 10_000);
+        assert_eq!(parsed.stats, report.stats);
+        assert_eq!(parsed.peak_bytes, Some(2_048));
+    }
+
+    #[test]
+    fn handles_patterns_in_answers() {
+        let report = PartReport {
+            part: 1,
+            status: PartStatus::Solved,
+            answer: Some("@ @ @ ( ) ms (2s @ 5 samples)\nline".into()),
+            error: None,
+            duration: Duration::from_millis(100),
+            samples: 1,
+            stats: None,
+            peak_bytes: None,
+        };
+
+        let line = JsonValue::from(&report).stringify().unwrap();
+        let parsed = parse_reports(&line);
+        let parsed = parsed[0].as_ref().unwrap();
+
+        assert_eq!(parsed.answer, report.answer);
+        assert_eq!(parsed.duration, Duration::from_millis(100));
+    }
+
+    #[test]
+    fn handles_unsolved_parts() {
+        let reports = parse_reports(
+            r#"{ "part": 1, "status": "unsolved", "answer": null, "nanos": 10, "samples": 1 }"#,
+        );
+        let report = reports[0].as_ref().unwrap();
+        assert_eq!(report.status, PartStatus::Unsolved);
+        assert_eq!(report.answer, None);
+    }
+
+    #[test]
+    fn round_trips_failed_parts() {
+        let report = PartReport {
+            part: 1,
+            status: PartStatus::Failed,
+            answer: None,
+            error: Some("panicked: invalid digit".into()),
+            duration: Duration::from_millis(1),
+            samples: 1,
+            stats: None,
+            peak_bytes: None,
+        };
+
+        let line = JsonValue::from(&report).stringify().unwrap();
+        let parsed = parse_reports(&line);
+        let parsed = parsed[0].as_ref().unwrap();
+
+        assert_eq!(parsed.status, PartStatus::Failed);
+        assert_eq!(parsed.error, report.error);
+    }
+
+    #[test]
+    fn skips_empty_lines_and_reports_malformed_ones() {
+        let reports = parse_reports("\nPart 1: 42 (1ms)\n");
+        assert_eq!(reports.len(), 1);
+        assert!(reports[0].is_err());
+    }
+}
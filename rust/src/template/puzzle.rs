@@ -0,0 +1,109 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[cfg(feature = "today")]
+use chrono::{Datelike, FixedOffset, Utc};
+
+use crate::template::{Day, Year};
+
+#[cfg(feature = "today")]
+const SERVER_UTC_OFFSET: i32 = -5;
+
+/// Identifies a single puzzle, i.e. a [`Day`] of a [`Year`] that has a puzzle.
+///
+/// # Display
+/// This value displays as `<year>-<day>`, which is also the name of the solution binary.
+///
+/// ```
+/// # use advent_of_code::template::{Day, PuzzleId, Year};
+/// let puzzle = PuzzleId::new(Year::new(2025).unwrap(), Day::new(8).unwrap()).unwrap();
+/// assert_eq!(puzzle.to_string(), "2025-08")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PuzzleId {
+    pub year: Year,
+    pub day: Day,
+}
+
+impl PuzzleId {
+    /// Creates a [`PuzzleId`] if the day has a puzzle in the provided year,
+    /// returns [`None`] otherwise.
+    pub const fn new(year: Year, day: Day) -> Option<Self> {
+        if !day.exists_in(year) {
+            return None;
+        }
+        Some(Self { year, day })
+    }
+}
+
+#[cfg(feature = "today")]
+impl PuzzleId {
+    /// Returns the current puzzle if today is a puzzle day of december, `None` otherwise.
+    pub fn today() -> Option<Self> {
+        let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
+        let today = Utc::now().with_timezone(&offset);
+        if today.month() != 12 {
+            return None;
+        }
+
+        let year = Year::new(u16::try_from(today.year()).ok()?)?;
+        let day = Day::new(u8::try_from(today.day()).ok()?)?;
+        Self::new(year, day)
+    }
+}
+
+impl Display for PuzzleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.year, self.day)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for PuzzleId {
+    type Err = PuzzleIdFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (year, day) = s.split_once('-').ok_or(PuzzleIdFromStrError)?;
+        let year = year.parse().map_err(|_| PuzzleIdFromStrError)?;
+        let day = day.parse().map_err(|_| PuzzleIdFromStrError)?;
+        Self::new(year, day).ok_or(PuzzleIdFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`PuzzleId`].
+#[derive(Debug)]
+pub struct PuzzleIdFromStrError;
+
+impl Error for PuzzleIdFromStrError {}
+
+impl Display for PuzzleIdFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a puzzle in the form `<year>-<day>`, e.g. `2025-01`")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::PuzzleId;
+    use crate::{day, year};
+
+    #[test]
+    fn rejects_days_outside_of_year() {
+        assert!(PuzzleId::new(year!(2025), day!(12)).is_some());
+        assert!(PuzzleId::new(year!(2025), day!(13)).is_none());
+        assert!(PuzzleId::new(year!(2024), day!(25)).is_some());
+    }
+
+    #[test]
+    fn round_trips_through_string() {
+        let puzzle = PuzzleId::new(year!(2024), day!(7)).unwrap();
+        assert_eq!(puzzle.to_string(), "2024-07");
+        assert_eq!("2024-07".parse::<PuzzleId>().ok(), Some(puzzle));
+        assert!("2025-13".parse::<PuzzleId>().is_err());
+        assert!("07".parse::<PuzzleId>().is_err());
+    }
+}
@@ -1,11 +1,18 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
-use std::{fs, io};
+use std::{
+    collections::HashMap, fmt::Display, fs, io, path::PathBuf, str::FromStr, time::Duration,
+};
 
-use crate::template::Day;
-use crate::template::timings::Timings;
+use tinyjson::JsonValue;
 
-static MARKER: &str = "<!--- benchmarking table --->";
+use crate::template::timings::{PartTiming, Timing, Timings};
+use crate::template::{PuzzleId, Year, crate_dir};
+
+/// Every year has its own table, delimited by a marker that contains the year.
+fn marker(year: Year) -> String {
+    format!("<!--- benchmarking table {year} --->")
+}
 
 #[allow(dead_code)]
 #[derive(Debug)]
@@ -14,6 +21,15 @@ pub enum Error {
     IO(io::Error),
 }
 
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(message) => f.write_str(message),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
 impl From<std::io::Error> for Error {
     fn from(e: std::io::Error) -> Self {
         Error::IO(e)
@@ -21,17 +37,19 @@ impl From<std::io::Error> for Error {
 }
 
 pub struct TablePosition {
-    pos_start: usize,
-    pos_end: usize,
+    pub(super) pos_start: usize,
+    pub(super) pos_end: usize,
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(puzzle: PuzzleId) -> String {
+    format!("./src/bin/{puzzle}.rs")
 }
 
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+/// Locates a table delimited by a marker. The table ends at the last occurence of the marker,
+/// so a single marker is replaced with a table.
+pub(super) fn locate_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
 
     if matches.len() > 2 {
         return Err(Error::Parser(
@@ -39,10 +57,11 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
         ));
     }
 
-    let pos_start = matches
-        .first()
-        .map(|m| m.0)
-        .ok_or_else(|| Error::Parser("Could not find table start position.".into()))?;
+    let pos_start = matches.first().map(|m| m.0).ok_or_else(|| {
+        Error::Parser(format!(
+            "Could not find table start position, add `{marker}` to the README."
+        ))
+    })?;
 
     let pos_end = matches
         .last()
@@ -52,78 +71,403 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
-    let header = format!("{prefix} Benchmarks");
+/// Location of the layout of the benchmark tables, next to `Cargo.toml`.
+fn get_config_path() -> PathBuf {
+    crate_dir().join("benchmarks.json")
+}
+
+/// A column of the benchmark table, next to the day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Column {
+    Parse,
+    Part1,
+    Part2,
+    /// Total duration of the day.
+    Total,
+    /// Number of samples of the parse step and both parts.
+    Samples,
+    /// Speedup of the total duration against the previous run of the day.
+    Speedup,
+    /// Peak heap usage of the day, as measured by `cargo time --dhat`.
+    Memory,
+}
+
+impl Column {
+    fn header(self) -> &'static str {
+        match self {
+            Column::Parse => "Parse",
+            Column::Part1 => "Part 1",
+            Column::Part2 => "Part 2",
+            Column::Total => "Total",
+            Column::Samples => "Samples",
+            Column::Speedup => "Speedup",
+            Column::Memory => "Memory",
+        }
+    }
+}
+
+impl FromStr for Column {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Column::Parse),
+            "part_1" => Ok(Column::Part1),
+            "part_2" => Ok(Column::Part2),
+            "total" => Ok(Column::Total),
+            "samples" => Ok(Column::Samples),
+            "speedup" => Ok(Column::Speedup),
+            "memory" => Ok(Column::Memory),
+            s => Err(format!(
+                "unknown column `{s}`, expected `parse`, `part_1`, `part_2`, `total`, `samples`, `speedup` or `memory`."
+            )),
+        }
+    }
+}
+
+/// Order of the days in the benchmark table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Sort {
+    Day,
+    /// Slowest total duration first.
+    Slowest,
+    /// Fastest total duration first.
+    Fastest,
+}
+
+impl FromStr for Sort {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "day" => Ok(Sort::Day),
+            "slowest" => Ok(Sort::Slowest),
+            "fastest" => Ok(Sort::Fastest),
+            s => Err(format!(
+                "unknown sort `{s}`, expected `day`, `slowest` or `fastest`."
+            )),
+        }
+    }
+}
+
+/// Layout of the benchmark tables, read from `benchmarks.json`:
+/// `{ "columns": ["part_1", "part_2", "total"], "sort": "slowest", "highlight_slowest": 3,
+/// "show_total": true, "titles": { "2025": "Benchmarks" } }`. Every key is optional.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableConfig {
+    pub columns: Vec<Column>,
+    pub sort: Sort,
+    /// Number of slowest days that are highlighted.
+    pub highlight_slowest: usize,
+    /// Whether the total duration of all days is shown below the table.
+    pub show_total: bool,
+    /// Titles of the tables of years, `Benchmarks (<year>)` unless set.
+    pub titles: HashMap<Year, String>,
+}
+
+impl Default for TableConfig {
+    fn default() -> Self {
+        Self {
+            columns: vec![Column::Parse, Column::Part1, Column::Part2],
+            sort: Sort::Day,
+            highlight_slowest: 0,
+            show_total: true,
+            titles: HashMap::new(),
+        }
+    }
+}
+
+impl TableConfig {
+    /// Read the layout of the benchmark tables. If the file is not present, returns the default.
+    pub fn read_from_file() -> Result<Self, String> {
+        match fs::read_to_string(get_config_path()) {
+            Ok(contents) => TableConfig::from_str(&contents),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(TableConfig::default()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    fn title(&self, year: Year) -> String {
+        self.titles
+            .get(&year)
+            .cloned()
+            .unwrap_or_else(|| format!("Benchmarks ({year})"))
+    }
+}
+
+impl FromStr for TableConfig {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let json = JsonValue::from_str(s).or(Err("not valid JSON file."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let mut config = TableConfig::default();
+
+        if let Some(columns) = json.get("columns") {
+            config.columns = columns
+                .get::<Vec<JsonValue>>()
+                .ok_or("expected `columns` to be an array.")?
+                .iter()
+                .map(|column| {
+                    column
+                        .get::<String>()
+                        .ok_or("expected `columns` to contain strings.".to_string())
+                        .and_then(|column| Column::from_str(column))
+                })
+                .collect::<Result<_, _>>()?;
+        }
+
+        if let Some(sort) = json.get("sort") {
+            config.sort = sort
+                .get::<String>()
+                .ok_or("expected `sort` to be a string.")?
+                .parse()?;
+        }
+
+        if let Some(highlight_slowest) = json.get("highlight_slowest") {
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let highlight_slowest = highlight_slowest
+                .get::<f64>()
+                .filter(|n| **n >= 0.0)
+                .map(|n| *n as usize)
+                .ok_or("expected `highlight_slowest` to be a positive number.")?;
+            config.highlight_slowest = highlight_slowest;
+        }
+
+        if let Some(show_total) = json.get("show_total") {
+            config.show_total = *show_total
+                .get::<bool>()
+                .ok_or("expected `show_total` to be a boolean.")?;
+        }
+
+        if let Some(titles) = json.get("titles") {
+            let titles = titles
+                .get::<HashMap<String, JsonValue>>()
+                .ok_or("expected `titles` to be an object.")?;
+
+            for (year, title) in titles {
+                let year =
+                    Year::from_str(year).or(Err(format!("`{year}` is not a valid year.")))?;
+                let title = title
+                    .get::<String>()
+                    .ok_or(format!("expected title of {year} to be a string."))?;
+                config.titles.insert(year, title.clone());
+            }
+        }
+
+        Ok(config)
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("`{duration:.1?}`")
+}
+
+fn format_part(part: Option<&PartTiming>, missing: &str) -> String {
+    part.map_or_else(|| missing.into(), |part| format_duration(part.duration))
+}
+
+/// Formats a number of bytes with a binary unit, e.g. `1.5 MiB`.
+#[allow(clippy::cast_precision_loss)]
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("`{bytes} B`")
+    } else {
+        format!("`{value:.1} {}`", UNITS[unit])
+    }
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn format_cell(column: Column, timing: &Timing, timings: &Timings) -> String {
+    match column {
+        Column::Parse => format_part(timing.parse.as_ref(), "-"),
+        Column::Part1 => format_part(timing.part_1.as_ref(), "`-`"),
+        Column::Part2 => format_part(timing.part_2.as_ref(), "`-`"),
+        Column::Total => format_duration(Duration::from_nanos(timing.total_nanos as u64)),
+        Column::Samples => {
+            let samples = |part: Option<&PartTiming>| {
+                part.map_or_else(|| "-".into(), |part| part.samples.to_string())
+            };
+            format!(
+                "`{} / {} / {}`",
+                samples(timing.parse.as_ref()),
+                samples(timing.part_1.as_ref()),
+                samples(timing.part_2.as_ref())
+            )
+        }
+        Column::Speedup => timings
+            .previous(timing.day)
+            .filter(|previous| previous.total_nanos > 0.0 && timing.total_nanos > 0.0)
+            .map_or_else(
+                || "-".into(),
+                |previous| format!("`{:.2}×`", previous.total_nanos / timing.total_nanos),
+            ),
+        Column::Memory => timing.peak_bytes().map_or_else(|| "-".into(), format_bytes),
+    }
+}
+
+fn construct_table(
+    prefix: &str,
+    year: Year,
+    timings: &Timings,
+    total_millis: f64,
+    config: &TableConfig,
+) -> String {
+    let header = format!("{prefix} {}", config.title(year));
+
+    let columns = config.columns.iter().map(|column| column.header());
+    let alignments = config.columns.iter().map(|_| ":---:");
 
     let mut lines: Vec<String> = vec![
-        MARKER.into(),
+        marker(year),
         header,
         String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
+        format!(
+            "| {} |",
+            ["Day"]
+                .into_iter()
+                .chain(columns)
+                .collect::<Vec<_>>()
+                .join(" | ")
+        ),
+        format!(
+            "| {} |",
+            [":---:"]
+                .into_iter()
+                .chain(alignments)
+                .collect::<Vec<_>>()
+                .join(" | ")
+        ),
     ];
 
-    for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
-        lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
+    let mut rows: Vec<&Timing> = timings
+        .data
+        .iter()
+        .filter(|timing| PuzzleId::new(year, timing.day).is_some())
+        .collect();
+
+    let mut slowest = rows.clone();
+    slowest.sort_by(|a, b| b.total_nanos.total_cmp(&a.total_nanos));
+    slowest.truncate(config.highlight_slowest);
+
+    match config.sort {
+        Sort::Day => rows.sort_by_key(|timing| timing.day),
+        Sort::Slowest => rows.sort_by(|a, b| b.total_nanos.total_cmp(&a.total_nanos)),
+        Sort::Fastest => rows.sort_by(|a, b| a.total_nanos.total_cmp(&b.total_nanos)),
+    }
+
+    for timing in rows {
+        let Some(puzzle) = PuzzleId::new(year, timing.day) else {
+            continue;
+        };
+
+        let mut day = format!(
+            "[Day {}]({})",
             timing.day.into_inner(),
-            path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
-        ));
+            get_path_for_bin(puzzle)
+        );
+        if slowest.iter().any(|slow| slow.day == timing.day) {
+            day = format!("**{day}** 🐢");
+        }
+
+        let cells: Vec<String> = config
+            .columns
+            .iter()
+            .map(|column| format_cell(*column, timing, timings))
+            .collect();
+        lines.push(format!("| {day} | {} |", cells.join(" | ")));
     }
 
-    lines.push(String::new());
-    lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
+    if config.show_total {
+        lines.push(String::new());
+        lines.push(format!("**Total: {total_millis:.2}ms**"));
+    }
+    lines.push(marker(year));
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+fn update_content(
+    s: &mut String,
+    year: Year,
+    timings: &Timings,
+    total_millis: f64,
+    config: &TableConfig,
+) -> Result<(), Error> {
+    let positions = locate_table(s, &marker(year))?;
+    let table = construct_table("##", year, timings, total_millis, config);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
-pub fn update(timings: Timings) -> Result<(), Error> {
-    let path = "README.md";
-    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+/// Update the benchmark table of a year in the README, laid out as configured in
+/// `benchmarks.json`.
+pub fn update(year: Year, timings: Timings) -> Result<(), Error> {
+    let config = TableConfig::read_from_file()
+        .map_err(|e| Error::Parser(format!("invalid benchmarks.json: {e}")))?;
+    let path = crate_dir().join("README.md");
+    let mut readme = String::from_utf8_lossy(&fs::read(&path)?).to_string();
     let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
+    update_content(&mut readme, year, &timings, total_millis, &config)?;
     fs::write(path, &readme)?;
     Ok(())
 }
 
-#[cfg(feature = "test_lib")]
+#[cfg(all(test, feature = "test_lib"))]
 mod tests {
-    use super::{MARKER, update_content};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use std::str::FromStr;
+    use std::time::Duration;
+
+    use super::{Column, Sort, TableConfig, format_bytes, marker, update_content};
+    use crate::template::timings::{Metadata, PartTiming, Timing, Timings};
+    use crate::{day, year};
+
+    fn millis(millis: u64) -> Option<PartTiming> {
+        Some(PartTiming::once(Duration::from_millis(millis)))
+    }
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
                     day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
+                    parse: None,
+                    part_1: millis(10),
+                    part_2: millis(20),
                     total_nanos: 3e+10,
+                    metadata: Metadata::default(),
                 },
                 Timing {
                     day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
+                    parse: millis(5),
+                    part_1: millis(30),
+                    part_2: millis(40),
                     total_nanos: 7e+10,
+                    metadata: Metadata::default(),
                 },
                 Timing {
                     day: day!(4),
-                    part_1: Some("40ms".into()),
-                    part_2: Some("50ms".into()),
+                    parse: None,
+                    part_1: millis(40),
+                    part_2: millis(50),
                     total_nanos: 9e+10,
+                    metadata: Metadata::default(),
                 },
             ],
+            history: vec![],
         }
     }
 
@@ -131,53 +475,207 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            year!(2025),
+            &get_mock_timings(),
+            190.0,
+            &TableConfig::default(),
+        )
+        .unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
-        let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        let marker = marker(year!(2025));
+        let mut s = format!("{} {} {}", marker, marker, marker);
+        update_content(
+            &mut s,
+            year!(2025),
+            &get_mock_timings(),
+            190.0,
+            &TableConfig::default(),
+        )
+        .unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
-        let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.contains("## Benchmarks"), true);
+        let marker = marker(year!(2025));
+        let mut s = format!("foo\nbar\n{}{}\nbaz", marker, marker);
+        update_content(
+            &mut s,
+            year!(2025),
+            &get_mock_timings(),
+            190.0,
+            &TableConfig::default(),
+        )
+        .unwrap();
+        assert!(s.contains("## Benchmarks (2025)"));
+    }
+
+    #[test]
+    fn updates_only_table_of_year() {
+        let (marker_2024, marker_2025) = (marker(year!(2024)), marker(year!(2025)));
+        let mut s = format!("{marker_2024}\n{marker_2025}");
+        update_content(
+            &mut s,
+            year!(2025),
+            &get_mock_timings(),
+            190.0,
+            &TableConfig::default(),
+        )
+        .unwrap();
+        assert_eq!(s.matches(&marker_2024).count(), 1);
+        assert_eq!(s.matches(&marker_2025).count(), 2);
+        assert!(!s.contains("## Benchmarks (2024)"));
+        assert!(s.contains("## Benchmarks (2025)"));
     }
 
     #[test]
     fn updates_existing_benchmarks() {
-        let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
+        let marker = marker(year!(2025));
+        let mut s = format!("foo\nbar\n{}{}\nbaz", marker, marker);
+        update_content(
+            &mut s,
+            year!(2025),
+            &get_mock_timings(),
+            190.0,
+            &TableConfig::default(),
+        )
+        .unwrap();
+        update_content(
+            &mut s,
+            year!(2025),
+            &get_mock_timings(),
+            190.0,
+            &TableConfig::default(),
+        )
+        .unwrap();
+        assert_eq!(s.matches(&marker).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
 
     #[test]
     fn format_benchmarks() {
-        let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        let marker = marker(year!(2025));
+        let mut s = format!("foo\nbar\n{}\n{}\nbaz", marker, marker);
+        update_content(
+            &mut s,
+            year!(2025),
+            &get_mock_timings(),
+            190.0,
+            &TableConfig::default(),
+        )
+        .unwrap();
         let expected = [
             "foo",
             "bar",
-            "<!--- benchmarking table --->",
-            "## Benchmarks",
+            "<!--- benchmarking table 2025 --->",
+            "## Benchmarks (2025)",
             "",
-            "| Day | Part 1 | Part 2 |",
-            "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| Day | Parse | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: | :---: |",
+            "| [Day 1](./src/bin/2025-01.rs) | - | `10.0ms` | `20.0ms` |",
+            "| [Day 2](./src/bin/2025-02.rs) | `5.0ms` | `30.0ms` | `40.0ms` |",
+            "| [Day 4](./src/bin/2025-04.rs) | - | `40.0ms` | `50.0ms` |",
             "",
             "**Total: 190.00ms**",
-            "<!--- benchmarking table --->",
+            "<!--- benchmarking table 2025 --->",
             "baz",
         ]
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn parses_config() {
+        let config = TableConfig::from_str(
+            r#"{ "columns": ["part_1", "total", "memory"], "sort": "slowest", "highlight_slowest": 2, "show_total": false, "titles": { "2025": "Speed" } }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.columns,
+            vec![Column::Part1, Column::Total, Column::Memory]
+        );
+        assert_eq!(config.sort, Sort::Slowest);
+        assert_eq!(config.highlight_slowest, 2);
+        assert!(!config.show_total);
+        assert_eq!(
+            config.titles.get(&year!(2025)).map(String::as_str),
+            Some("Speed")
+        );
+
+        assert_eq!(TableConfig::from_str("{}").unwrap(), TableConfig::default());
+    }
+
+    #[test]
+    fn parses_example_config() {
+        let config = TableConfig::from_str(include_str!("../../benchmarks.example.json")).unwrap();
+        assert_eq!(config.columns.len(), 7);
+    }
+
+    #[test]
+    fn rejects_invalid_config() {
+        assert!(TableConfig::from_str(r#"{ "columns": ["parse", "lines"] }"#).is_err());
+        assert!(TableConfig::from_str(r#"{ "sort": "random" }"#).is_err());
+        assert!(TableConfig::from_str(r#"{ "highlight_slowest": -1 }"#).is_err());
+        assert!(TableConfig::from_str(r#"{ "titles": { "1999": "Party" } }"#).is_err());
+    }
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(512), "`512 B`");
+        assert_eq!(format_bytes(1_536), "`1.5 KiB`");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "`3.0 MiB`");
+    }
+
+    #[test]
+    fn formats_configured_benchmarks() {
+        let mut timings = get_mock_timings();
+        timings.data[0].part_1 = Some(PartTiming {
+            samples: 10_000,
+            peak_bytes: Some(2_048),
+            ..PartTiming::once(Duration::from_millis(10))
+        });
+        // NOTE: the previous run of day 1 took twice as long.
+        timings.history = vec![
+            Timing {
+                total_nanos: 6e+10,
+                ..timings.data[0].clone()
+            },
+            timings.data[0].clone(),
+        ];
+
+        let config = TableConfig {
+            columns: vec![
+                Column::Total,
+                Column::Samples,
+                Column::Speedup,
+                Column::Memory,
+            ],
+            sort: Sort::Slowest,
+            highlight_slowest: 1,
+            show_total: false,
+            titles: [(year!(2025), "⏱️ Timings".to_string())].into(),
+        };
+
+        let marker = marker(year!(2025));
+        let mut s = format!("{marker}{marker}");
+        update_content(&mut s, year!(2025), &timings, 190.0, &config).unwrap();
+        let expected = [
+            "<!--- benchmarking table 2025 --->",
+            "## ⏱️ Timings",
+            "",
+            "| Day | Total | Samples | Speedup | Memory |",
+            "| :---: | :---: | :---: | :---: | :---: |",
+            "| **[Day 4](./src/bin/2025-04.rs)** 🐢 | `90.0s` | `- / 1 / 1` | - | - |",
+            "| [Day 2](./src/bin/2025-02.rs) | `70.0s` | `1 / 1 / 1` | - | - |",
+            "| [Day 1](./src/bin/2025-01.rs) | `30.0s` | `- / 10000 / 1` | `2.00×` | `2.0 KiB` |",
+            "<!--- benchmarking table 2025 --->",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
 }
@@ -0,0 +1,112 @@
+/// Module that updates the readme with the stars collected in a year.
+/// The table follows the format of the `advent-readme-stars` action, which uses the same marker.
+use std::fs;
+
+use crate::template::readme_benchmarks::{Error, locate_table};
+use crate::template::stars::Stars;
+use crate::template::{Year, crate_dir};
+
+const MARKER: &str = "<!--- advent_readme_stars table --->";
+
+fn construct_table(prefix: &str, year: Year, stars: &Stars) -> String {
+    let header = format!("{prefix} {year} Results");
+
+    let mut lines: Vec<String> = vec![
+        MARKER.into(),
+        header,
+        String::new(),
+        "| Day | Part 1 | Part 2 |".into(),
+        "| :---: | :---: | :---: |".into(),
+    ];
+
+    for day in year.days() {
+        let count = stars.get(day);
+        if count == 0 {
+            continue;
+        }
+        let star = |part: u8| if count >= part { "⭐" } else { " " };
+        lines.push(format!(
+            "| [Day {}](https://adventofcode.com/{}/day/{}) | {} | {} |",
+            day.into_inner(),
+            year,
+            day.into_inner(),
+            star(1),
+            star(2)
+        ));
+    }
+
+    lines.push(String::new());
+    lines.push(format!("**Total: {}⭐**", stars.total()));
+    lines.push(MARKER.into());
+
+    lines.join("\n")
+}
+
+fn update_content(s: &mut String, year: Year, stars: &Stars) -> Result<(), Error> {
+    let positions = locate_table(s, MARKER)?;
+    let table = construct_table("##", year, stars);
+    s.replace_range(positions.pos_start..positions.pos_end, &table);
+    Ok(())
+}
+
+pub fn update(year: Year, stars: &Stars) -> Result<(), Error> {
+    let path = crate_dir().join("README.md");
+    let mut readme = String::from_utf8_lossy(&fs::read(&path)?).to_string();
+    update_content(&mut readme, year, stars)?;
+    fs::write(path, &readme)?;
+    Ok(())
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{MARKER, update_content};
+    use crate::template::stars::Stars;
+    use crate::{day, year};
+
+    fn get_mock_stars() -> Stars {
+        let mut stars = Stars::default();
+        stars.record(day!(1), 2);
+        stars.record(day!(2), 1);
+        stars.record(day!(4), 2);
+        stars
+    }
+
+    #[test]
+    #[should_panic]
+    fn errors_if_marker_not_present() {
+        let mut s = "# readme".to_string();
+        update_content(&mut s, year!(2025), &get_mock_stars()).unwrap();
+    }
+
+    #[test]
+    fn updates_existing_stars() {
+        let mut s = format!("foo\n{MARKER}\nbar");
+        update_content(&mut s, year!(2025), &get_mock_stars()).unwrap();
+        update_content(&mut s, year!(2025), &get_mock_stars()).unwrap();
+        assert_eq!(s.matches(MARKER).count(), 2);
+        assert_eq!(s.matches("## 2025 Results").count(), 1);
+    }
+
+    #[test]
+    fn format_stars() {
+        let mut s = format!("foo\n{MARKER}\nbar");
+        update_content(&mut s, year!(2025), &get_mock_stars()).unwrap();
+        let expected = [
+            "foo",
+            "<!--- advent_readme_stars table --->",
+            "## 2025 Results",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: |",
+            "| [Day 1](https://adventofcode.com/2025/day/1) | ⭐ | ⭐ |",
+            "| [Day 2](https://adventofcode.com/2025/day/2) | ⭐ |   |",
+            "| [Day 4](https://adventofcode.com/2025/day/4) | ⭐ | ⭐ |",
+            "",
+            "**Total: 5⭐**",
+            "<!--- advent_readme_stars table --->",
+            "bar",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+}
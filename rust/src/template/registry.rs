@@ -0,0 +1,80 @@
+/// Collects solutions so they can be run in-process instead of spawning a binary per day.
+///
+/// Every `solution!` invocation defines a `SOLUTION` constant. The build script includes each
+/// solution binary as a module of the `aoc-runner` binary, which passes these constants to
+/// [`Registry::new`].
+use crate::template::PuzzleId;
+use crate::template::part_report::PartReport;
+use crate::template::runner::RunOptions;
+
+/// Runs every part of a solution against an input.
+pub type SolutionRunner = fn(&str, RunOptions) -> Vec<PartReport>;
+
+/// A solution registered via [`solution!`](crate::solution).
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub puzzle: PuzzleId,
+    pub run: SolutionRunner,
+}
+
+/// The set of solutions compiled into the current binary.
+#[derive(Clone, Default)]
+pub struct Registry {
+    solutions: Vec<Solution>,
+}
+
+impl Registry {
+    pub fn new(solutions: &[Solution]) -> Self {
+        let mut solutions = solutions.to_vec();
+        solutions.sort_unstable_by_key(|solution| solution.puzzle);
+        Self { solutions }
+    }
+
+    /// Returns the solution for a puzzle, if it has been registered.
+    pub fn get(&self, puzzle: PuzzleId) -> Option<&Solution> {
+        self.solutions
+            .binary_search_by_key(&puzzle, |solution| solution.puzzle)
+            .ok()
+            .map(|i| &self.solutions[i])
+    }
+
+    /// Iterates all registered solutions, ordered by puzzle.
+    pub fn iter(&self) -> impl Iterator<Item = &Solution> {
+        self.solutions.iter()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{Registry, Solution};
+    use crate::template::{Day, PuzzleId};
+    use crate::{day, year};
+
+    fn solution(day: u8) -> Solution {
+        Solution {
+            puzzle: PuzzleId::new(year!(2025), Day::new(day).unwrap()).unwrap(),
+            run: |_, _| vec![],
+        }
+    }
+
+    #[test]
+    fn finds_registered_solutions() {
+        let registry = Registry::new(&[solution(3), solution(1)]);
+        let puzzle = PuzzleId::new(year!(2025), day!(3)).unwrap();
+        assert_eq!(registry.get(puzzle).map(|s| s.puzzle), Some(puzzle));
+        assert!(
+            registry
+                .get(PuzzleId::new(year!(2025), day!(2)).unwrap())
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn iterates_in_order() {
+        let registry = Registry::new(&[solution(3), solution(1), solution(2)]);
+        let days: Vec<_> = registry.iter().map(|s| s.puzzle.day).collect();
+        assert_eq!(days, vec![day!(1), day!(2), day!(3)]);
+    }
+}
@@ -0,0 +1,422 @@
+/// Standalone reports of the timings, answers and stars of a year, see `cargo export`.
+/// Reports render as CSV or JSON to feed other tools, or as Markdown to publish them.
+use std::{collections::HashMap, fmt::Write, str::FromStr, time::Duration};
+
+use tinyjson::JsonValue;
+
+use crate::template::answers::Answers;
+use crate::template::part_report::PARSE_PART;
+use crate::template::stars::Stars;
+use crate::template::timings::Timings;
+use crate::template::{Day, Year, all_days};
+
+/// Language the solutions are written in.
+pub const LANGUAGE: &str = "Rust";
+
+/// Format of a report.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Csv,
+    Json,
+    Markdown,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "csv" => Ok(Format::Csv),
+            "json" => Ok(Format::Json),
+            "md" | "markdown" => Ok(Format::Markdown),
+            _ => Err(format!(
+                "unknown report format `{s}`, expected `csv`, `json` or `md`."
+            )),
+        }
+    }
+}
+
+/// Completion of a day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    Unsolved,
+    Partial,
+    Complete,
+}
+
+impl Status {
+    fn as_str(self) -> &'static str {
+        match self {
+            Status::Unsolved => "unsolved",
+            Status::Partial => "partial",
+            Status::Complete => "complete",
+        }
+    }
+}
+
+/// Report of a single day.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DayReport {
+    pub day: Day,
+    pub parse: Option<Duration>,
+    pub part_1: Option<Duration>,
+    pub part_2: Option<Duration>,
+    pub total: Option<Duration>,
+    pub answers: [Option<String>; 2],
+    pub stars: u8,
+}
+
+impl DayReport {
+    pub fn status(&self) -> Status {
+        match self.stars {
+            0 => Status::Unsolved,
+            1 => Status::Partial,
+            _ => Status::Complete,
+        }
+    }
+}
+
+/// Report of a year, with a row for every day that has timings, answers or stars.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Report {
+    pub year: Year,
+    pub days: Vec<DayReport>,
+}
+
+impl Report {
+    /// Collect the report of a year. The stars of a day are at least its number of known answers,
+    /// as stars are only recorded when fetched or submitted via the template.
+    pub fn new(year: Year, timings: &Timings, answers: &Answers, stars: &Stars) -> Self {
+        let days = all_days(year)
+            .filter_map(|day| {
+                let timing = timings.data.iter().find(|timing| timing.day == day);
+                let answers = [1, 2].map(|part| answers.get(day, part).map(String::from));
+                let known = answers.iter().flatten().count();
+                let stars = stars.get(day).max(u8::try_from(known).unwrap_or(2));
+
+                if timing.is_none() && stars == 0 {
+                    return None;
+                }
+
+                let duration = |part: u8| timing.and_then(|timing| timing.duration(part));
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                Some(DayReport {
+                    day,
+                    parse: duration(PARSE_PART),
+                    part_1: duration(1),
+                    part_2: duration(2),
+                    total: timing.map(|timing| Duration::from_nanos(timing.total_nanos as u64)),
+                    answers,
+                    stars,
+                })
+            })
+            .collect();
+
+        Self { year, days }
+    }
+
+    /// Sum up the total duration of all days.
+    pub fn total(&self) -> Duration {
+        self.days.iter().filter_map(|day| day.total).sum()
+    }
+
+    /// Sum up the stars of all days.
+    pub fn stars(&self) -> u32 {
+        self.days.iter().map(|day| u32::from(day.stars)).sum()
+    }
+
+    pub fn render(&self, format: Format) -> String {
+        match format {
+            Format::Csv => self.to_csv(),
+            Format::Json => self.to_json(),
+            Format::Markdown => self.to_markdown(),
+        }
+    }
+
+    fn to_csv(&self) -> String {
+        let mut csv = String::from(
+            "day,language,parse_nanos,part_1_nanos,part_2_nanos,total_nanos,part_1_answer,part_2_answer,stars,status\n",
+        );
+
+        let nanos = |duration: Option<Duration>| {
+            duration.map_or_else(String::new, |duration| duration.as_nanos().to_string())
+        };
+
+        for day in &self.days {
+            let [answer_1, answer_2] = day
+                .answers
+                .each_ref()
+                .map(|answer| csv_field(answer.as_deref().unwrap_or_default()));
+
+            let _ = writeln!(
+                csv,
+                "{},{LANGUAGE},{},{},{},{},{answer_1},{answer_2},{},{}",
+                day.day.into_inner(),
+                nanos(day.parse),
+                nanos(day.part_1),
+                nanos(day.part_2),
+                nanos(day.total),
+                day.stars,
+                day.status().as_str()
+            );
+        }
+
+        csv
+    }
+
+    fn to_json(&self) -> String {
+        #[allow(clippy::cast_precision_loss)]
+        let nanos = |duration: Option<Duration>| {
+            duration.map_or(JsonValue::Null, |duration| {
+                JsonValue::Number(duration.as_nanos() as f64)
+            })
+        };
+        let string = |value: &str| JsonValue::String(value.into());
+
+        let days = self
+            .days
+            .iter()
+            .map(|day| {
+                let [answer_1, answer_2] = day
+                    .answers
+                    .each_ref()
+                    .map(|answer| answer.as_deref().map_or(JsonValue::Null, string));
+
+                JsonValue::Object(HashMap::from([
+                    ("day".into(), JsonValue::Number(day.day.into_inner().into())),
+                    ("language".into(), string(LANGUAGE)),
+                    ("parse_nanos".into(), nanos(day.parse)),
+                    ("part_1_nanos".into(), nanos(day.part_1)),
+                    ("part_2_nanos".into(), nanos(day.part_2)),
+                    ("total_nanos".into(), nanos(day.total)),
+                    (
+                        "answers".into(),
+                        JsonValue::Object(HashMap::from([
+                            ("part_1".into(), answer_1),
+                            ("part_2".into(), answer_2),
+                        ])),
+                    ),
+                    ("stars".into(), JsonValue::Number(day.stars.into())),
+                    ("status".into(), string(day.status().as_str())),
+                ]))
+            })
+            .collect();
+
+        let complete_days = self
+            .days
+            .iter()
+            .filter(|day| day.status() == Status::Complete)
+            .count();
+
+        #[allow(clippy::cast_precision_loss)]
+        let report = JsonValue::Object(HashMap::from([
+            (
+                "year".into(),
+                JsonValue::Number(self.year.into_inner().into()),
+            ),
+            ("language".into(), string(LANGUAGE)),
+            ("days".into(), JsonValue::Array(days)),
+            (
+                "totals".into(),
+                JsonValue::Object(HashMap::from([
+                    ("nanos".into(), nanos(Some(self.total()))),
+                    ("stars".into(), JsonValue::Number(self.stars().into())),
+                    (
+                        "complete_days".into(),
+                        JsonValue::Number(complete_days as f64),
+                    ),
+                ])),
+            ),
+        ]));
+
+        let mut json = report.format().unwrap_or_default();
+        json.push('\n');
+        json
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn to_markdown(&self) -> String {
+        let mut md = format!("## Advent of Code {} ({LANGUAGE})\n\n", self.year);
+        md.push_str("| Day | Parse | Part 1 | Part 2 | Total | Answer 1 | Answer 2 | Stars |\n");
+        md.push_str("| :---: | :---: | :---: | :---: | :---: | :---: | :---: | :---: |\n");
+
+        let duration = |duration: Option<Duration>| {
+            duration.map_or_else(|| "-".into(), |duration| format!("`{duration:.1?}`"))
+        };
+        let answer =
+            |answer: &Option<String>| answer.as_deref().map_or_else(|| "-".into(), markdown_code);
+
+        for day in &self.days {
+            let _ = writeln!(
+                md,
+                "| Day {} | {} | {} | {} | {} | {} | {} | {} |",
+                day.day.into_inner(),
+                duration(day.parse),
+                duration(day.part_1),
+                duration(day.part_2),
+                duration(day.total),
+                answer(&day.answers[0]),
+                answer(&day.answers[1]),
+                "⭐".repeat(day.stars.into())
+            );
+        }
+
+        let _ = writeln!(
+            md,
+            "\n**Total: {:.2}ms · {}⭐**",
+            self.total().as_nanos() as f64 / 1_000_000_f64,
+            self.stars()
+        );
+        md
+    }
+}
+
+/// Quotes a CSV field if it contains separators, quotes or line breaks.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Formats a value as inline code in a Markdown table cell. The fence is longer than any run of
+/// backticks in the value, pipes are escaped so they do not split the cell, and line breaks are
+/// replaced by spaces so they do not end the row.
+fn markdown_code(value: &str) -> String {
+    let longest_run = value.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    let fence = "`".repeat(longest_run + 1);
+    let padding = if value.starts_with('`') || value.ends_with('`') {
+        " "
+    } else {
+        ""
+    };
+    let value = value.replace('|', "\\|").replace(['\n', '\r'], " ");
+    format!("{fence}{padding}{value}{padding}{fence}")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use std::{str::FromStr, time::Duration};
+
+    use tinyjson::JsonValue;
+
+    use super::{Format, Report, Status, csv_field, markdown_code};
+    use crate::template::answers::Answers;
+    use crate::template::stars::Stars;
+    use crate::template::timings::{Metadata, PartTiming, Timing, Timings};
+    use crate::{day, year};
+
+    fn get_mock_report() -> Report {
+        let timings = Timings {
+            data: vec![Timing {
+                day: day!(1),
+                parse: Some(PartTiming::once(Duration::from_micros(5))),
+                part_1: Some(PartTiming::once(Duration::from_micros(10))),
+                part_2: Some(PartTiming::once(Duration::from_micros(20))),
+                total_nanos: 35_000_f64,
+                metadata: Metadata::default(),
+            }],
+            history: vec![],
+        };
+
+        let mut answers = Answers::default();
+        answers.set(day!(1), 1, "42");
+        answers.set(day!(1), 2, "1,337");
+        answers.set(day!(3), 1, "7");
+
+        let mut stars = Stars::default();
+        stars.record(day!(2), 1);
+
+        Report::new(year!(2025), &timings, &answers, &stars)
+    }
+
+    #[test]
+    fn collects_days() {
+        let report = get_mock_report();
+        assert_eq!(report.days.len(), 3);
+
+        let [day_1, day_2, day_3] = [&report.days[0], &report.days[1], &report.days[2]];
+        assert_eq!(day_1.day, day!(1));
+        assert_eq!(day_1.part_1, Some(Duration::from_micros(10)));
+        assert_eq!(day_1.total, Some(Duration::from_micros(35)));
+        assert_eq!(day_1.status(), Status::Complete);
+        assert_eq!(day_2.total, None);
+        assert_eq!(day_2.status(), Status::Partial);
+        assert_eq!(day_3.answers, [Some("7".into()), None]);
+        assert_eq!(day_3.stars, 1);
+
+        assert_eq!(report.total(), Duration::from_micros(35));
+        assert_eq!(report.stars(), 4);
+    }
+
+    #[test]
+    fn parses_formats() {
+        assert_eq!(Format::from_str("csv"), Ok(Format::Csv));
+        assert_eq!(Format::from_str("md"), Ok(Format::Markdown));
+        assert!(Format::from_str("html").is_err());
+    }
+
+    #[test]
+    fn renders_csv() {
+        let csv = get_mock_report().render(Format::Csv);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                "day,language,parse_nanos,part_1_nanos,part_2_nanos,total_nanos,part_1_answer,part_2_answer,stars,status",
+                "1,Rust,5000,10000,20000,35000,42,\"1,337\",2,complete",
+                "2,Rust,,,,,,,1,partial",
+                "3,Rust,,,,,7,,1,partial",
+            ]
+        );
+    }
+
+    #[test]
+    fn renders_json() {
+        let json = JsonValue::from_str(&get_mock_report().render(Format::Json)).unwrap();
+        assert_eq!(json["year"], JsonValue::Number(2025.0));
+        assert_eq!(json["language"], JsonValue::String("Rust".into()));
+        assert_eq!(json["totals"]["stars"], JsonValue::Number(4.0));
+        assert_eq!(json["totals"]["complete_days"], JsonValue::Number(1.0));
+
+        let day_1 = &json["days"][0];
+        assert_eq!(day_1["day"], JsonValue::Number(1.0));
+        assert_eq!(day_1["part_2_nanos"], JsonValue::Number(20_000.0));
+        assert_eq!(
+            day_1["answers"]["part_2"],
+            JsonValue::String("1,337".into())
+        );
+        assert_eq!(day_1["status"], JsonValue::String("complete".into()));
+        assert_eq!(json["days"][1]["total_nanos"], JsonValue::Null);
+    }
+
+    #[test]
+    fn renders_markdown() {
+        let md = get_mock_report().render(Format::Markdown);
+        assert!(md.starts_with("## Advent of Code 2025 (Rust)\n"));
+        assert!(md.contains(
+            "| Day 1 | `5.0µs` | `10.0µs` | `20.0µs` | `35.0µs` | `42` | `1,337` | ⭐⭐ |"
+        ));
+        assert!(md.contains("| Day 2 | - | - | - | - | - | - | ⭐ |"));
+        assert!(md.ends_with("**Total: 0.04ms · 4⭐**\n"));
+    }
+
+    #[test]
+    fn quotes_csv_fields() {
+        assert_eq!(csv_field("42"), "42");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn escapes_markdown_code() {
+        assert_eq!(markdown_code("42"), "`42`");
+        assert_eq!(markdown_code("a|b"), "`a\\|b`");
+        assert_eq!(markdown_code("a`b"), "``a`b``");
+        assert_eq!(markdown_code("`a``"), "``` `a`` ```");
+        assert_eq!(markdown_code("a\nb"), "`a b`");
+    }
+}
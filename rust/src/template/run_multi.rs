@@ -1,41 +1,82 @@
-use std::{collections::HashSet, io};
-
-use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day};
+use std::collections::{HashMap, HashSet};
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+use crate::template::output::{self, outln};
+use crate::template::part_report::{PARSE_PART, PartReport, PartStatus};
+use crate::template::registry::Registry;
+use crate::template::runner::RunOptions;
+use crate::template::{
+    ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day, PuzzleId, Year, crate_dir, try_read_file,
+};
 
 use super::{
     all_days,
-    timings::{Timing, Timings},
+    timings::{Metadata, PartTiming, Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
-    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
-
-    let mut need_space = false;
+/// Determines how [`run_multi`] runs the solution of a day.
+pub enum Executor<'a> {
+    /// Run solutions in-process, using the solutions compiled into the current binary.
+    InProcess(&'a Registry),
+    /// Run every solution in its own binary via `cargo run`. With `dhat`, solutions are built
+    /// with the `dhat` profile and measure their peak heap usage.
+    Isolated { is_release: bool, dhat: bool },
+}
 
+/// Runs the solutions of the provided days, `jobs` days at a time.
+/// The output of every day is buffered while days run in parallel, and printed in day order.
+pub fn run_multi(
+    year: Year,
+    days_to_run: &HashSet<Day>,
+    executor: &Executor,
+    options: RunOptions,
+    jobs: usize,
+) -> Option<Timings> {
     // NOTE: use non-duplicate, sorted day values.
-    all_days()
+    let puzzles: Vec<PuzzleId> = all_days(year)
         .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
-            }
-            need_space = true;
+        .filter_map(|day| PuzzleId::new(year, day))
+        .collect();
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
-
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+    let timings: Vec<Timing> = run_days(&puzzles, jobs, |puzzle| {
+        if Some(&puzzle) != puzzles.first() {
+            outln!();
+        }
 
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
+        outln!("{ANSI_BOLD}Day {}{ANSI_RESET}", puzzle.day);
+        outln!("------");
+
+        let timing = match executor {
+            Executor::InProcess(registry) => run_in_process(registry, puzzle, options),
+            Executor::Isolated { is_release, dhat } => {
+                let reports = child_commands::run_solution(puzzle, options, *is_release, *dhat)
+                    .unwrap_or_else(|e| {
+                        outln!("Could not run solution: {e:?}");
+                        vec![]
+                    });
+                (!reports.is_empty()).then(|| timing_from_reports(puzzle.day, &reports))
             }
-        });
+        };
 
-    if is_timed {
-        let timings = Timings { data: timings };
+        if timing.is_none() {
+            outln!("Not solved.");
+        }
+
+        timing
+    })
+    .into_iter()
+    .flatten()
+    .collect();
+
+    if options.timed {
+        let timings = Timings {
+            data: timings,
+            history: vec![],
+        };
         let total_millis = timings.total_millis();
         println!(
             "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
@@ -46,10 +87,117 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
     }
 }
 
+/// Runs a function for every puzzle on up to `jobs` threads and returns the results in order.
+/// The output of a puzzle is printed as soon as it and all puzzles before it are done.
+fn run_days<T: Send>(
+    puzzles: &[PuzzleId],
+    jobs: usize,
+    run_day: impl Fn(PuzzleId) -> T + Sync,
+) -> Vec<T> {
+    if jobs <= 1 {
+        return puzzles.iter().map(|puzzle| run_day(*puzzle)).collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(puzzles.len()) {
+            let sender = sender.clone();
+            let (next, run_day) = (&next, &run_day);
+            scope.spawn(move || {
+                while let Some(puzzle) = puzzles.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let (result, output) = output::capture(|| run_day(*puzzle));
+                    if sender.send((*puzzle, result, output)).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(sender);
+
+        let mut results = Vec::with_capacity(puzzles.len());
+        let mut pending = HashMap::new();
+
+        for (puzzle, result, output) in receiver {
+            pending.insert(puzzle, (result, output));
+
+            while let Some((result, output)) = puzzles
+                .get(results.len())
+                .and_then(|puzzle| pending.remove(puzzle))
+            {
+                print!("{output}");
+                let _ = io::stdout().flush();
+                results.push(result);
+            }
+        }
+
+        results
+    })
+}
+
+/// Run the registered solution for a puzzle in the current process.
+fn run_in_process(registry: &Registry, puzzle: PuzzleId, options: RunOptions) -> Option<Timing> {
+    let solution = registry.get(puzzle)?;
+
+    let input = match try_read_file("inputs", puzzle) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Could not read input file: {e}");
+            return None;
+        }
+    };
+
+    let reports = (solution.run)(&input, options);
+
+    // NOTE: a timed-out part keeps running on its own thread, competing with every later part.
+    if options.timed
+        && reports
+            .iter()
+            .any(|report| report.status == PartStatus::TimedOut)
+    {
+        outln!(
+            "{ANSI_ITALIC}A part timed out and keeps running in the background, timings of later parts are unreliable. Pass `--isolated` to run every day in its own process.{ANSI_RESET}"
+        );
+    }
+
+    Some(timing_from_reports(puzzle.day, &reports))
+}
+
+/// Summarise the reports of the parts of a day as a timing.
+pub fn timing_from_reports(day: Day, reports: &[PartReport]) -> Timing {
+    let find_part = |part: u8| {
+        reports
+            .iter()
+            .find(|report| report.part == part && report.status == PartStatus::Solved)
+    };
+    let part_timing = |part: u8| {
+        find_part(part).map(|report| PartTiming {
+            duration: report.duration,
+            samples: report.samples,
+            stats: report.stats,
+            peak_bytes: report.peak_bytes,
+        })
+    };
+
+    Timing {
+        day,
+        parse: part_timing(PARSE_PART),
+        part_1: part_timing(1),
+        part_2: part_timing(2),
+        #[allow(clippy::cast_precision_loss)]
+        total_nanos: reports
+            .iter()
+            .filter(|report| report.status == PartStatus::Solved)
+            .map(|report| report.duration.as_nanos() as f64)
+            .sum(),
+        metadata: Metadata::default(),
+    }
+}
+
 #[allow(dead_code)]
 #[derive(Debug)]
 pub enum Error {
-    BrokenPipe,
     IO(io::Error),
 }
 
@@ -60,198 +208,192 @@ impl From<std::io::Error> for Error {
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(puzzle: PuzzleId) -> PathBuf {
+    crate_dir().join(format!("src/bin/{puzzle}.rs"))
 }
 
-/// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// Solutions can also live in isolated binaries.
+/// This module encapsulates interaction with these binaries, both invoking them as well as reading the reports they emit.
 pub mod child_commands {
     use super::{Error, get_path_for_bin};
-    use crate::template::Day;
+    use crate::template::output::{self, out};
+    use crate::template::part_report::{PartReport, REPORT_FILE_ENV, read_reports};
+    use crate::template::runner::RunOptions;
+    use crate::template::{PuzzleId, crate_dir};
     use std::{
-        io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
-        thread,
+        env, fs,
+        process::{self, Command, Stdio},
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    /// Run the solution bin for a given day and collect the reports of its parts.
+    pub fn run_solution(
+        puzzle: PuzzleId,
+        options: RunOptions,
+        is_release: bool,
+        dhat: bool,
+    ) -> Result<Vec<PartReport>, Error> {
         // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
+        if !get_path_for_bin(puzzle).exists() {
             return Ok(vec![]);
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        let bin_name = puzzle.to_string();
+        let mut command = Command::new("cargo");
+        command
+            .args(["run", "--quiet", "--bin", &bin_name])
+            .current_dir(crate_dir());
 
-        if is_release {
-            args.push("--release");
-        }
-
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+        if dhat {
+            command.args(["--profile", "dhat", "--features", "dhat-heap"]);
+        } else if is_release {
+            command.arg("--release");
         }
 
-        // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
-
-        let mut cmd = Command::new("cargo")
-            .args(&args)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()?;
-
-        let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
-        let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
+        command.arg("--").args(solution_args(options));
+        run_reporting(command, &bin_name)
+    }
 
-        let mut output = vec![];
+    /// Returns the arguments that mirror the run options to a solution binary.
+    pub fn solution_args(options: RunOptions) -> Vec<String> {
+        let mut args = vec![];
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
-            });
-        });
-
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+        if options.timed {
+            // mirror `--time` flag to child invocations.
+            args.push("--time".into());
         }
 
-        thread.join().unwrap();
-        cmd.wait()?;
+        // NOTE: the child gives up on parts itself, its abandoned parts end with the process.
+        if let Some(timeout) = options.timeout {
+            args.push("--timeout".into());
+            args.push(timeout.as_secs_f64().to_string());
+        }
 
-        Ok(output)
+        args
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
-        let mut timings = super::Timing {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
+    /// Run a command that invokes a solution binary and collect the reports of its parts.
+    pub fn run_reporting(mut command: Command, bin_name: &str) -> Result<Vec<PartReport>, Error> {
+        // the child appends one JSON record per part to the report file.
+        let report_path =
+            env::temp_dir().join(format!("aoc-report-{bin_name}-{}.jsonl", process::id()));
+        let _ = fs::remove_file(&report_path);
+
+        command.env(REPORT_FILE_ENV, &report_path);
+
+        // NOTE: when the output is captured, the output of the child is captured as well.
+        let status = if output::is_captured() {
+            command.output().map(|output| {
+                out!("{}", String::from_utf8_lossy(&output.stdout));
+                out!("{}", String::from_utf8_lossy(&output.stderr));
+                output.status
+            })
+        } else {
+            command
+                .stdout(Stdio::inherit())
+                .stderr(Stdio::inherit())
+                .status()
         };
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
-
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
-
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
+        let reports = status.map_err(Error::from).and_then(|_| {
+            if !report_path.exists() {
+                return Ok(vec![]);
+            }
 
-                timings.total_nanos += nanos;
-            });
+            Ok(read_reports(&report_path)?
+                .into_iter()
+                .filter_map(|report| {
+                    report
+                        .inspect_err(|e| eprintln!("Could not parse report: {e}"))
+                        .ok()
+                })
+                .collect())
+        });
 
-        timings
+        let _ = fs::remove_file(&report_path);
+        reports
     }
+}
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use std::thread;
+    use std::time::Duration;
+
+    use super::{run_days, timing_from_reports};
+    use crate::template::part_report::{PARSE_PART, PartReport, PartStatus};
+    use crate::template::{PuzzleId, all_days};
+    use crate::{day, year};
+
+    fn report(part: u8, status: PartStatus, nanos: u64) -> PartReport {
+        PartReport {
+            part,
+            status,
+            answer: (status == PartStatus::Solved).then(|| "1".into()),
+            error: None,
+            duration: Duration::from_nanos(nanos),
+            samples: 10,
+            stats: None,
+            peak_bytes: None,
+        }
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .next_back()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+    #[test]
+    fn builds_timings_from_reports() {
+        let timing = timing_from_reports(
+            day!(1),
+            &[
+                report(1, PartStatus::Solved, 74),
+                report(2, PartStatus::Solved, 74_130_000),
+            ],
+        );
+        assert_eq!(timing.total_nanos, 74_130_074_f64);
+        assert_eq!(timing.part_1.unwrap().duration, Duration::from_nanos(74));
+        assert_eq!(timing.part_2.unwrap().samples, 10);
     }
 
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
-            );
-        }};
+    #[test]
+    fn skips_unsolved_parts() {
+        let timing = timing_from_reports(
+            day!(1),
+            &[
+                report(1, PartStatus::Unsolved, 100),
+                report(2, PartStatus::Solved, 2_000_000_000),
+            ],
+        );
+        assert_eq!(timing.total_nanos, 2_000_000_000_f64);
+        assert!(timing.part_1.is_none());
+        assert_eq!(timing.part_2.unwrap().duration, Duration::from_secs(2));
     }
 
-    #[cfg(feature = "test_lib")]
-    mod tests {
-        use super::parse_exec_time;
-
-        use crate::day;
-
-        #[test]
-        fn parses_execution_times() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
-        }
+    #[test]
+    fn includes_parse_step() {
+        let timing = timing_from_reports(
+            day!(1),
+            &[
+                report(PARSE_PART, PartStatus::Solved, 1_000),
+                report(1, PartStatus::Solved, 2_000),
+                report(2, PartStatus::Solved, 3_000),
+            ],
+        );
+        assert_eq!(timing.total_nanos, 6_000_f64);
+        assert_eq!(timing.parse.unwrap().duration, Duration::from_micros(1));
+        assert_eq!(timing.part_1.unwrap().duration, Duration::from_micros(2));
+    }
 
-        #[test]
-        fn parses_with_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
-        }
+    #[test]
+    fn runs_days_in_parallel_in_order() {
+        let puzzles: Vec<PuzzleId> = all_days(year!(2025))
+            .filter_map(|day| PuzzleId::new(year!(2025), day))
+            .collect();
+
+        // later days finish first.
+        let days = run_days(&puzzles, 4, |puzzle| {
+            thread::sleep(Duration::from_millis(u64::from(
+                13 - puzzle.day.into_inner(),
+            )));
+            puzzle.day
+        });
 
-        #[test]
-        fn parses_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
-        }
+        assert_eq!(days, all_days(year!(2025)).collect::<Vec<_>>());
     }
 }
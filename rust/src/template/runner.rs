@@ -1,85 +1,514 @@
 /// Encapsulates code that interacts with solution functions.
+use std::cell::{Cell, RefCell};
 use std::fmt::Display;
 use std::hint::black_box;
 use std::io::{Write, stdout};
-use std::process::Output;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
 use std::time::{Duration, Instant};
 use std::{cmp, env, process};
 
-use crate::template::ANSI_BOLD;
-use crate::template::{ANSI_ITALIC, ANSI_RESET, Day, aoc_cli};
+use crate::template::answers::{self, Answers};
+use crate::template::guesses::{Guess, Guesses, SubmitVerdict};
+use crate::template::output::{out, outln};
+use crate::template::part_report::{PARSE_PART, PartReport, PartStatus};
+use crate::template::stars::Stars;
+use crate::template::stats::BenchStats;
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, PuzzleId, aoc_client, readme_stars};
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// Time spent warming up before sampling a benched function.
+const WARMUP_TIME: Duration = Duration::from_millis(100);
+const MAX_WARMUP_ITERATIONS: u128 = 1000;
+
+/// Approximate time spent sampling a benched function.
+const BENCH_TIME: Duration = Duration::from_secs(1);
+const MIN_SAMPLES: u128 = 10;
+const MAX_SAMPLES: u128 = 10000;
+
+/// Stack size of the threads that run parts with a timeout, matching the main thread on Linux.
+const PART_STACK_SIZE: usize = 8 * 1024 * 1024;
+
+/// Options that control how a solution part is run.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RunOptions {
+    /// Bench the part instead of running it once.
+    pub timed: bool,
+    /// Give up on a part if it does not return in time.
+    pub timeout: Option<Duration>,
+}
+
+impl RunOptions {
+    /// Reads the options passed to a solution binary.
+    pub fn from_args() -> Self {
+        let args: Vec<String> = env::args().collect();
+
+        let timeout = args.iter().position(|x| x == "--timeout").map(|i| {
+            match args.get(i + 1).map(|s| parse_timeout(s)) {
+                Some(Ok(timeout)) => timeout,
+                Some(Err(e)) => {
+                    eprintln!("Invalid `--timeout`: {e}");
+                    process::exit(1);
+                }
+                None => {
+                    eprintln!("Unexpected command-line input. Format: --timeout <seconds>");
+                    process::exit(1);
+                }
+            }
+        });
+
+        Self {
+            timed: args.iter().any(|x| x == "--time"),
+            timeout,
+        }
+    }
+
+    /// Falls back to the timeout of a solution if no timeout was passed.
+    #[must_use]
+    pub fn with_default_timeout(self, timeout: Option<Duration>) -> Self {
+        Self {
+            timeout: self.timeout.or(timeout),
+            ..self
+        }
+    }
+}
+
+/// Parses a timeout in seconds, e.g. `10` or `2.5`. The units `s` and `ms` may be appended.
+pub fn parse_timeout(s: &str) -> Result<Duration, String> {
+    let (value, unit) = match s.strip_suffix("ms") {
+        Some(value) => (value, 1e-3),
+        None => (s.strip_suffix('s').unwrap_or(s), 1.0),
+    };
+
+    value
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .and_then(|value| Duration::try_from_secs_f64(value * unit).ok())
+        .filter(|timeout| !timeout.is_zero())
+        .ok_or_else(|| format!("`{s}` is not a valid timeout, expecting a number of seconds."))
+}
+
+/// Extends the lifetime of the input of a solution to the remainder of the process, so that
+/// parts that are abandoned on a timeout can keep using it. Only leak inputs of runs with a
+/// timeout, as the memory is never freed.
+pub fn leak_input(input: &str) -> &'static str {
+    Box::leak(input.into())
+}
+
+/// Extends the lifetime of the parsed input or the parameters of a solution, see [`leak_input`].
+pub fn leak_parsed<P>(parsed: P) -> &'static P {
+    Box::leak(Box::new(parsed))
+}
+
+/// The outcome of a single run of a solution part.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PartOutcome {
+    Solved(String),
+    /// The part returned `None`.
+    Unsolved,
+    /// The part returned an error or panicked.
+    Failed(String),
+}
+
+/// Values that solution parts can return. `Option<T>` returns `None` for parts that are not
+/// solved yet, `Result<T, E>` reports its error as a failure of the part.
+pub trait PartOutput {
+    fn outcome(&self) -> PartOutcome;
+}
+
+impl<T: Display> PartOutput for Option<T> {
+    fn outcome(&self) -> PartOutcome {
+        match self {
+            Some(answer) => PartOutcome::Solved(answer.to_string()),
+            None => PartOutcome::Unsolved,
+        }
+    }
+}
+
+impl<T: Display, E: Display> PartOutput for Result<T, E> {
+    fn outcome(&self) -> PartOutcome {
+        match self {
+            Ok(answer) => PartOutcome::Solved(answer.to_string()),
+            Err(e) => PartOutcome::Failed(e.to_string()),
+        }
+    }
+}
+
+/// Emit the report of a step of a solution binary and submit its answer if requested.
+pub fn emit_report(report: &PartReport, puzzle: PuzzleId) {
+    if let Err(e) = report.emit() {
+        eprintln!("Failed to write report: {e}");
+    }
+
+    if report.part != PARSE_PART
+        && let Some(answer) = &report.answer
+    {
+        submit_result(answer, puzzle, report.part);
+    }
+}
+
+/// Run the `parse` step of a solution on the current thread and print its duration, ignoring
+/// the timeout of the options. Returns the parsed input, which is passed to both parts and may
+/// borrow from the input, or `None` if the step panicked.
+pub fn run_parse_with<'a, P>(
+    func: impl Fn(&'a str) -> P,
+    input: &'a str,
+    options: RunOptions,
+) -> (Option<P>, PartReport) {
+    let first_run = || run_once(|| func(input));
+    parse_report(&func, input, options, first_run)
+}
+
+/// Run the `parse` step of a solution like [`run_parse_with`], but give up if it does not return
+/// within the timeout of the options. The input is borrowed for the remainder of the process, as
+/// the step keeps running in the background on a timeout.
+pub fn run_parse_with_timeout<P>(
+    func: impl Fn(&'static str) -> P + Clone + Send + 'static,
+    input: &'static str,
+    options: RunOptions,
+) -> (Option<P>, PartReport)
+where
+    P: Send + 'static,
+{
+    let first_run = {
+        let func = func.clone();
+        move || run_with_timeout(func, input, options.timeout)
+    };
+    parse_report(&func, input, options, first_run)
+}
+
+fn parse_report<'a, P>(
+    func: impl Fn(&'a str) -> P,
+    input: &'a str,
+    options: RunOptions,
+    first_run: impl FnOnce() -> (Result<P, Aborted>, Duration),
+) -> (Option<P>, PartReport) {
+    let (parsed, duration, stats, peak_bytes) = run_timed(&func, input, options, first_run, |_| {
+        out!("Parse:");
+    });
+    let samples = stats.map_or(1, |stats| stats.samples + stats.outliers);
+
+    out!("\r");
+    match &parsed {
+        Ok(_) => outln!("Parse:{}", format_duration(&duration, samples)),
+        Err(e) => outln!("Parse: ✖ {e}{}", format_duration(&duration, samples)),
+    }
+    if let Some(stats) = &stats {
+        print_stats(stats);
+    }
+
+    let report = PartReport {
+        part: PARSE_PART,
+        status: match &parsed {
+            Ok(_) => PartStatus::Solved,
+            Err(Aborted::TimedOut) => PartStatus::TimedOut,
+            Err(Aborted::Panicked(_)) => PartStatus::Failed,
+        },
+        answer: None,
+        error: parsed.as_ref().err().map(ToString::to_string),
+        duration,
+        samples,
+        stats,
+        peak_bytes,
+    };
+
+    (parsed.ok(), report)
+}
+
+/// Run a solution part on the current thread with the provided options, ignoring their timeout,
+/// and print its result, checked against the known answer of the part.
+pub fn run_part_with<I, R>(
+    func: impl Fn(I) -> R,
+    input: I,
+    puzzle: PuzzleId,
+    part: u8,
+    options: RunOptions,
+) -> PartReport
+where
+    I: Copy,
+    R: PartOutput,
+{
+    let first_run = || run_once(|| func(input));
+    part_report(&func, input, puzzle, part, options, first_run)
+}
+
+/// Run a solution part like [`run_part_with`], but give up if it does not return within the
+/// timeout of the options. The input is borrowed for the remainder of the process, as the part
+/// keeps running in the background on a timeout.
+pub fn run_part_with_timeout<I, R>(
+    func: impl Fn(I) -> R + Clone + Send + 'static,
+    input: I,
+    puzzle: PuzzleId,
+    part: u8,
+    options: RunOptions,
+) -> PartReport
+where
+    I: Copy + Send + 'static,
+    R: PartOutput + Send + 'static,
+{
+    let first_run = {
+        let func = func.clone();
+        move || run_with_timeout(func, input, options.timeout)
+    };
+    part_report(&func, input, puzzle, part, options, first_run)
+}
+
+fn part_report<I: Copy, R: PartOutput>(
+    func: impl Fn(I) -> R,
+    input: I,
+    puzzle: PuzzleId,
+    part: u8,
+    options: RunOptions,
+    first_run: impl FnOnce() -> (Result<R, Aborted>, Duration),
+) -> PartReport {
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let (result, duration, stats, peak_bytes) =
+        run_timed(&func, input, options, first_run, |result| {
+            print_result(&result.outcome(), &part_str, "");
+        });
+    let samples = stats.map_or(1, |stats| stats.samples + stats.outliers);
+
+    let outcome = match &result {
+        Ok(result) => result.outcome(),
+        Err(e) => PartOutcome::Failed(e.to_string()),
+    };
+    let answer = match &outcome {
+        PartOutcome::Solved(answer) => Some(answer.clone()),
+        _ => None,
+    };
+    let verdict = answers::check_answer(puzzle, part, answer.as_deref());
+
+    print_result(
+        &outcome,
+        &part_str,
+        &format!("{verdict}{}", format_duration(&duration, samples)),
+    );
+    if let Some(stats) = &stats {
+        print_stats(stats);
+    }
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    let (status, error) = match outcome {
+        PartOutcome::Solved(_) => (PartStatus::Solved, None),
+        PartOutcome::Unsolved => (PartStatus::Unsolved, None),
+        PartOutcome::Failed(e) if matches!(result, Err(Aborted::TimedOut)) => {
+            (PartStatus::TimedOut, Some(e))
+        }
+        PartOutcome::Failed(e) => (PartStatus::Failed, Some(e)),
+    };
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    PartReport {
+        part,
+        status,
+        answer,
+        error,
+        duration,
+        samples,
+        stats,
+        peak_bytes,
     }
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+/// Run a solution part. The behavior differs depending on whether the part is timed:
+///  1. by default, the function is executed once.
+///  2. when timed, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+///     The returned duration is the median of the samples.
+///
+/// The first run is done by `first_run`, which catches panics and timeouts and measures its
+/// duration. If it does not return a value, the part is not benched. With `dhat-heap`, the peak
+/// heap usage of the first run is returned too.
+#[allow(clippy::type_complexity)]
 fn run_timed<I: Copy, T>(
     func: impl Fn(I) -> T,
     input: I,
+    options: RunOptions,
+    first_run: impl FnOnce() -> (Result<T, Aborted>, Duration),
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
-    let timer = Instant::now();
-    let result = {
+) -> (
+    Result<T, Aborted>,
+    Duration,
+    Option<BenchStats>,
+    Option<u64>,
+) {
+    let (result, base_time, peak_bytes) = {
         #[cfg(feature = "dhat-heap")]
         let _profiler = dhat::Profiler::new_heap();
 
-        func(input)
+        let (result, base_time) = first_run();
+        (result, base_time, peak_heap_bytes())
+    };
+
+    let result = match result {
+        Ok(result) => result,
+        Err(e) => return (Err(e), base_time, None, peak_bytes),
     };
-    let base_time = timer.elapsed();
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+    if options.timed {
+        let stats = bench(func, input, &base_time, options.timeout);
+        (Ok(result), stats.median, Some(stats), peak_bytes)
     } else {
-        (base_time, 1)
+        (Ok(result), base_time, None, peak_bytes)
+    }
+}
+
+/// Returns the peak heap usage since the heap profiler started, if built with `dhat-heap`.
+fn peak_heap_bytes() -> Option<u64> {
+    #[cfg(feature = "dhat-heap")]
+    return Some(dhat::HeapStats::get().max_bytes as u64);
+
+    #[cfg(not(feature = "dhat-heap"))]
+    None
+}
+
+/// The reason why a run of a part did not return a value.
+#[derive(Debug)]
+enum Aborted {
+    Panicked(String),
+    TimedOut,
+}
+
+impl Display for Aborted {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Aborted::Panicked(message) => f.write_str(message),
+            Aborted::TimedOut => f.write_str("timed out"),
+        }
+    }
+}
+
+/// Run a function once, catching panics, and measure its duration.
+fn run_once<T>(func: impl FnOnce() -> T) -> (Result<T, Aborted>, Duration) {
+    let timer = Instant::now();
+    let result = catch_panic(func).map_err(Aborted::Panicked);
+    (result, timer.elapsed())
+}
+
+/// Run a part once, see [`run_once`]. With a timeout, the part runs on its own thread, which is
+/// abandoned if the part does not return in time. The thread keeps running in the background
+/// until the process exits, which is why inputs are leaked for the remainder of the process.
+fn run_with_timeout<I, T>(
+    func: impl FnOnce(I) -> T + Send + 'static,
+    input: I,
+    timeout: Option<Duration>,
+) -> (Result<T, Aborted>, Duration)
+where
+    I: Send + 'static,
+    T: Send + 'static,
+{
+    let Some(timeout) = timeout else {
+        return run_once(|| func(input));
     };
 
-    (result, run.0, run.1)
+    let deadline = Instant::now() + timeout;
+    let (sender, receiver) = mpsc::channel();
+    let spawned = thread::Builder::new()
+        .stack_size(PART_STACK_SIZE)
+        .spawn(move || {
+            let _ = sender.send(run_once(|| func(input)));
+        });
+
+    if let Err(e) = spawned {
+        let message = format!("could not spawn thread: {e}");
+        return (Err(Aborted::Panicked(message)), Duration::ZERO);
+    }
+
+    // NOTE: the duration is measured by the thread, so it does not include spawning the thread.
+    match receiver.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+        Ok((_, duration)) if duration > timeout => (Err(Aborted::TimedOut), timeout),
+        Ok(result) => result,
+        Err(RecvTimeoutError::Timeout) => (Err(Aborted::TimedOut), timeout),
+        Err(RecvTimeoutError::Disconnected) => (
+            Err(Aborted::Panicked("the part exited unexpectedly".into())),
+            Duration::ZERO,
+        ),
+    }
+}
+
+thread_local! {
+    static CATCHING_PANIC: Cell<bool> = const { Cell::new(false) };
+    static PANIC_LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
 }
 
-fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
+/// Run a function and catch a panic, which is returned as an error with its message and location.
+/// Caught panics are not printed by the panic hook, other panics are printed as usual.
+fn catch_panic<T>(func: impl FnOnce() -> T) -> Result<T, String> {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING_PANIC.get() {
+                PANIC_LOCATION.set(info.location().map(ToString::to_string));
+            } else {
+                default_hook(info);
+            }
+        }));
+    });
+
+    let was_catching = CATCHING_PANIC.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(func));
+    CATCHING_PANIC.set(was_catching);
+
+    result.map_err(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .copied()
+            .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+            .unwrap_or("unknown cause");
+
+        match PANIC_LOCATION.take() {
+            Some(location) => format!("panicked at {location}: {message}"),
+            None => format!("panicked: {message}"),
+        }
+    })
+}
+
+/// Bench a function. Caches and branch predictors are warmed up for approx. 100ms before
+/// sampling, the number of samples is then estimated from the warmup runs. With a timeout,
+/// benching stops once it elapses, keeping the samples taken so far, but at least one.
+fn bench<I: Copy, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    timeout: Option<Duration>,
+) -> BenchStats {
     let mut stdout = stdout();
 
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+    out!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
     let _ = stdout.flush();
 
+    let deadline = timeout.map(|timeout| Instant::now() + timeout);
+    let is_expired = || deadline.is_some_and(|deadline| Instant::now() >= deadline);
+
+    let warmup_iterations = (WARMUP_TIME.as_nanos() / cmp::max(base_time.as_nanos(), 10))
+        .clamp(1, MAX_WARMUP_ITERATIONS);
+
+    let warmup_timer = Instant::now();
+    let mut warmed_up = 0;
+    while warmed_up < warmup_iterations && !is_expired() {
+        black_box(func(black_box(input)));
+        warmed_up += 1;
+    }
+    let warm_time = warmup_timer.elapsed().as_nanos() / cmp::max(warmed_up, 1);
+
     let bench_iterations =
-        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+        (BENCH_TIME.as_nanos() / cmp::max(warm_time, 10)).clamp(MIN_SAMPLES, MAX_SAMPLES);
 
     let mut timers: Vec<Duration> = vec![];
 
     for _ in 0..bench_iterations {
+        if !timers.is_empty() && is_expired() {
+            break;
+        }
         let timer = Instant::now();
         black_box(func(black_box(input)));
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
-}
-
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+    BenchStats::from_samples(&timers).unwrap_or_default()
 }
 
 fn format_duration(duration: &Duration, samples: u128) -> String {
@@ -90,36 +519,60 @@ fn format_duration(duration: &Duration, samples: u128) -> String {
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+fn print_stats(stats: &BenchStats) {
+    let BenchStats {
+        min,
+        max,
+        mean,
+        p95,
+        stddev,
+        outliers,
+        ..
+    } = stats;
+
+    outln!(
+        "  {ANSI_ITALIC}min {min:.1?} · mean {mean:.1?} · p95 {p95:.1?} · max {max:.1?} · σ {stddev:.1?} · {outliers} outliers{ANSI_RESET}"
+    );
+}
+
+fn print_result(outcome: &PartOutcome, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
-    match result {
-        Some(result) => {
-            if result.to_string().contains('\n') {
+    match outcome {
+        PartOutcome::Solved(result) => {
+            if result.contains('\n') {
                 let str = format!("{part}: ▼ {duration_str}");
                 if is_intermediate_result {
-                    print!("{str}");
+                    out!("{str}");
                 } else {
-                    print!("\r");
-                    println!("{str}");
-                    println!("{result}");
+                    out!("\r");
+                    outln!("{str}");
+                    outln!("{result}");
                 }
             } else {
                 let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}");
                 if is_intermediate_result {
-                    print!("{str}");
+                    out!("{str}");
                 } else {
-                    print!("\r");
-                    println!("{str}");
+                    out!("\r");
+                    outln!("{str}");
                 }
             }
         }
-        None => {
+        PartOutcome::Unsolved => {
+            if is_intermediate_result {
+                out!("{part}: ✖");
+            } else {
+                out!("\r");
+                outln!("{part}: ✖             ");
+            }
+        }
+        PartOutcome::Failed(e) => {
             if is_intermediate_result {
-                print!("{part}: ✖");
+                out!("{part}: ✖");
             } else {
-                print!("\r");
-                println!("{part}: ✖             ");
+                out!("\r");
+                outln!("{part}: ✖ {e}{duration_str}");
             }
         }
     }
@@ -127,12 +580,12 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
 
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
+///  2. the result is not known to be wrong from previous guesses.
 fn submit_result<T: Display>(
     result: T,
-    day: Day,
+    puzzle: PuzzleId,
     part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+) -> Option<Result<String, aoc_client::AocClientError>> {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
@@ -155,13 +608,311 @@ fn submit_result<T: Display>(
         return None;
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
+    submit_answer(puzzle, part, &result.to_string())
+}
+
+/// Submit an answer, unless it is known to be wrong from previous guesses.
+/// The verdict is recorded in the guesses and, if correct, in the known answers.
+fn submit_answer(
+    puzzle: PuzzleId,
+    part: u8,
+    result: &str,
+) -> Option<Result<String, aoc_client::AocClientError>> {
+    let mut guesses = match Guesses::read_from_file(puzzle.year) {
+        Ok(guesses) => guesses,
+        Err(e) => {
+            eprintln!("Could not read previous guesses: {e}");
+            process::exit(1);
+        }
+    };
+
+    if let Err(reason) = guesses.check(puzzle.day, part, result) {
+        eprintln!("Refusing to submit: {reason}");
+        return None;
+    }
+
+    println!("Submitting result...");
+    let response = aoc_client::submit(puzzle, part, result);
+
+    if let Err(e) = &response {
+        eprintln!("Failed to submit result: {e}");
+    }
+
+    if let Some(verdict) = response
+        .as_ref()
+        .ok()
+        .and_then(|response| SubmitVerdict::from_response(response))
+    {
+        guesses.record(
+            puzzle.day,
+            part,
+            Guess {
+                answer: result.to_string(),
+                verdict,
+            },
+        );
+        if let Err(e) = guesses.store_file(puzzle.year) {
+            eprintln!("Failed to store guess: {e}");
+        }
+
+        if verdict == SubmitVerdict::Correct {
+            store_answer(puzzle, part, result);
+            store_star(puzzle, part);
+        }
+    }
+
+    Some(response)
+}
+
+/// Record the star of a solved part and update the stars table in the readme.
+fn store_star(puzzle: PuzzleId, part: u8) {
+    let stored = Stars::read_from_file(puzzle.year).and_then(|mut stars| {
+        stars.record(puzzle.day, part);
+        stars.store_file(puzzle.year).map_err(|e| e.to_string())?;
+        Ok(stars)
+    });
+
+    match stored {
+        Ok(stars) => {
+            if readme_stars::update(puzzle.year, &stars).is_err() {
+                eprintln!("Failed to update stars table.");
+            }
+        }
+        Err(e) => eprintln!("Failed to store star: {e}"),
+    }
+}
+
+/// Record the correct answer of a part in the known answers.
+fn store_answer(puzzle: PuzzleId, part: u8, answer: &str) {
+    let stored = Answers::read_from_file(puzzle.year).and_then(|mut answers| {
+        answers.set(puzzle.day, part, answer);
+        answers.store_file(puzzle.year).map_err(|e| e.to_string())
+    });
+
+    if let Err(e) = stored {
+        eprintln!("Failed to store answer: {e}");
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use std::thread;
+    use std::time::Duration;
+
+    use super::{
+        PartOutcome, PartOutput, RunOptions, parse_timeout, run_parse_with, run_parse_with_timeout,
+        run_part_with, run_part_with_timeout, submit_answer,
+    };
+    use crate::template::PuzzleId;
+    use crate::template::answers::Answers;
+    use crate::template::guesses::{Guesses, SubmitVerdict};
+    use crate::template::mock_server::{MockServer, Sandbox};
+    use crate::template::part_report::PartStatus;
+    use crate::template::stars::Stars;
+    use crate::{day, year};
+
+    #[test]
+    fn records_submissions() {
+        let puzzle = PuzzleId::new(year!(2025), day!(1)).unwrap();
+        let server = MockServer::builder().answer(puzzle, 1, "3").start();
+        let _sandbox = Sandbox::new(&server);
+
+        assert!(submit_answer(puzzle, 1, "4").unwrap().is_ok());
+        // known to be too high, so it is not submitted again.
+        assert!(submit_answer(puzzle, 1, "5").is_none());
+        assert!(submit_answer(puzzle, 1, "3").unwrap().is_ok());
+        assert_eq!(server.submissions().len(), 2);
+
+        let guesses = Guesses::read_from_file(puzzle.year).unwrap();
+        let verdicts: Vec<_> = guesses
+            .get(puzzle.day, 1)
+            .iter()
+            .map(|guess| guess.verdict)
+            .collect();
+        assert_eq!(verdicts, [SubmitVerdict::TooHigh, SubmitVerdict::Correct]);
+
+        let answers = Answers::read_from_file(puzzle.year).unwrap();
+        assert_eq!(answers.get(puzzle.day, 1), Some("3"));
+
+        let stars = Stars::read_from_file(puzzle.year).unwrap();
+        assert_eq!(stars.get(puzzle.day), 1);
+    }
+
+    #[test]
+    fn does_not_record_rate_limited_submissions() {
+        let puzzle = PuzzleId::new(year!(2025), day!(1)).unwrap();
+        let server = MockServer::builder().rate_limited().start();
+        let _sandbox = Sandbox::new(&server);
+
+        assert!(submit_answer(puzzle, 1, "3").unwrap().is_ok());
+        let guesses = Guesses::read_from_file(puzzle.year).unwrap();
+        assert!(guesses.get(puzzle.day, 1).is_empty());
+    }
+
+    #[test]
+    fn accepts_options_and_results() {
+        assert_eq!(Some(42).outcome(), PartOutcome::Solved("42".into()));
+        assert_eq!(None::<u8>.outcome(), PartOutcome::Unsolved);
+        assert_eq!(
+            Ok::<_, String>("abc").outcome(),
+            PartOutcome::Solved("abc".into())
+        );
+        assert_eq!(
+            Err::<u8, _>("invalid input").outcome(),
+            PartOutcome::Failed("invalid input".into())
+        );
+    }
+
+    #[test]
+    fn reports_errors_as_failures() {
+        let puzzle = PuzzleId::new(year!(2025), day!(1)).unwrap();
+        let report = run_part_with(
+            |input: &str| input.parse::<u64>(),
+            "abc",
+            puzzle,
+            1,
+            RunOptions::default(),
+        );
+        assert_eq!(report.status, PartStatus::Failed);
+        assert_eq!(report.answer, None);
+        assert_eq!(
+            report.error.as_deref(),
+            Some("invalid digit found in string")
         );
-        process::exit(1);
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    #[test]
+    fn catches_panics() {
+        let puzzle = PuzzleId::new(year!(2025), day!(1)).unwrap();
+        let report = run_part_with(
+            |input: &str| Some(input.parse::<u64>().unwrap()),
+            "abc",
+            puzzle,
+            1,
+            RunOptions {
+                timed: true,
+                timeout: None,
+            },
+        );
+        assert_eq!(report.status, PartStatus::Failed);
+        let error = report.error.unwrap();
+        assert!(error.starts_with("panicked at src/template/runner.rs:"));
+        assert!(error.ends_with("ParseIntError { kind: InvalidDigit }"));
+
+        let (parsed, report) = run_parse_with(
+            |_: &str| -> u64 { panic!("bad input") },
+            "",
+            RunOptions::default(),
+        );
+        assert!(parsed.is_none());
+        assert_eq!(report.status, PartStatus::Failed);
+        assert!(report.error.unwrap().ends_with(": bad input"));
+    }
+
+    #[test]
+    fn parses_timeouts() {
+        assert_eq!(parse_timeout("10"), Ok(Duration::from_secs(10)));
+        assert_eq!(parse_timeout("2.5s"), Ok(Duration::from_millis(2500)));
+        assert_eq!(parse_timeout("500ms"), Ok(Duration::from_millis(500)));
+        assert!(parse_timeout("0").is_err());
+        assert!(parse_timeout("-1").is_err());
+        assert!(parse_timeout("10m").is_err());
+    }
+
+    #[test]
+    fn prefers_passed_timeouts() {
+        let options = RunOptions {
+            timed: false,
+            timeout: Some(Duration::from_secs(1)),
+        };
+        assert_eq!(
+            options
+                .with_default_timeout(Some(Duration::from_secs(5)))
+                .timeout,
+            Some(Duration::from_secs(1))
+        );
+        assert_eq!(
+            RunOptions::default()
+                .with_default_timeout(Some(Duration::from_secs(5)))
+                .timeout,
+            Some(Duration::from_secs(5))
+        );
+    }
+
+    #[test]
+    fn times_out_parts() {
+        let puzzle = PuzzleId::new(year!(2025), day!(1)).unwrap();
+        let options = RunOptions {
+            timed: true,
+            timeout: Some(Duration::from_millis(50)),
+        };
+
+        let report = run_part_with_timeout(
+            |_: &str| {
+                thread::sleep(Duration::from_secs(5));
+                Some(1)
+            },
+            "",
+            puzzle,
+            1,
+            options,
+        );
+        assert_eq!(report.status, PartStatus::TimedOut);
+        assert_eq!(report.error.as_deref(), Some("timed out"));
+        assert_eq!(report.duration, Duration::from_millis(50));
+        assert_eq!(report.stats, None);
+
+        let report = run_part_with_timeout(|_: &str| Some(1), "", puzzle, 2, options);
+        assert_eq!(report.status, PartStatus::Solved);
+        assert_eq!(report.answer.as_deref(), Some("1"));
+    }
+
+    #[test]
+    fn times_out_parse_step() {
+        let options = RunOptions {
+            timed: false,
+            timeout: Some(Duration::from_millis(50)),
+        };
+
+        let (parsed, report) = run_parse_with_timeout(
+            |input: &str| {
+                thread::sleep(Duration::from_secs(5));
+                input.len()
+            },
+            "",
+            options,
+        );
+        assert!(parsed.is_none());
+        assert_eq!(report.status, PartStatus::TimedOut);
+        assert_eq!(report.error.as_deref(), Some("timed out"));
+
+        let (parsed, report) = run_parse_with_timeout(|input: &str| input.len(), "abc", options);
+        assert_eq!(parsed, Some(3));
+        assert_eq!(report.status, PartStatus::Solved);
+    }
+
+    #[test]
+    fn stops_benching_on_timeout() {
+        let puzzle = PuzzleId::new(year!(2025), day!(1)).unwrap();
+        let options = RunOptions {
+            timed: true,
+            timeout: Some(Duration::from_millis(100)),
+        };
+
+        // NOTE: without the timeout, the part would be sampled at least 10 times.
+        let report = run_part_with_timeout(
+            |_: &str| {
+                thread::sleep(Duration::from_millis(20));
+                Some(1)
+            },
+            "",
+            puzzle,
+            1,
+            options,
+        );
+        assert_eq!(report.status, PartStatus::Solved);
+        assert!(report.samples >= 1 && report.samples < 10);
+    }
 }
@@ -0,0 +1,195 @@
+/// Stars collected per day, used to render the progress table in the readme.
+///
+/// Stars are stored per year in `data/<year>/stars.json`, keyed by day: `{ "01": 2, "02": 1 }`.
+/// They are recorded when a submitted answer is correct, or taken from the calendar page of the
+/// Advent of Code website.
+use std::{collections::HashMap, fs, io, path::PathBuf, str::FromStr};
+
+use tinyjson::JsonValue;
+
+use crate::template::{Day, Year, data_path};
+
+fn get_stars_path(year: Year) -> PathBuf {
+    data_path(year, "stars.json")
+}
+
+/// Stars collected on all days of a year.
+#[derive(Clone, Debug, Default)]
+pub struct Stars {
+    pub data: HashMap<Day, u8>,
+}
+
+impl Stars {
+    /// Read the stars of a year. If the file is not present, returns no stars.
+    pub fn read_from_file(year: Year) -> Result<Self, String> {
+        match fs::read_to_string(get_stars_path(year)) {
+            Ok(contents) => Stars::from_str(&contents),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Stars::default()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// Write the stars of a year to its file.
+    pub fn store_file(&self, year: Year) -> io::Result<()> {
+        let path = get_stars_path(year);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = fs::File::create(path)?;
+        JsonValue::from(self).format_to(&mut file)
+    }
+
+    /// Returns the number of stars collected on a day.
+    pub fn get(&self, day: Day) -> u8 {
+        self.data.get(&day).copied().unwrap_or_default()
+    }
+
+    /// Record that a part was solved. Part two can only be solved after part one,
+    /// so solving it yields both stars.
+    pub fn record(&mut self, day: Day, part: u8) {
+        let stars = self.data.entry(day).or_default();
+        *stars = (*stars).max(part.min(2));
+    }
+
+    /// Merge stars from another source, keeping the most stars of every day.
+    pub fn merge(&mut self, other: &Stars) {
+        for (day, stars) in &other.data {
+            self.record(*day, *stars);
+        }
+    }
+
+    /// Returns the total number of stars.
+    pub fn total(&self) -> u32 {
+        self.data.values().map(|stars| u32::from(*stars)).sum()
+    }
+
+    /// Reads the stars from the calendar page of a year. Days are links with a class of
+    /// `calendar-day<n>`, and `calendar-complete` or `calendar-verycomplete` for one or two stars.
+    pub fn from_calendar(html: &str) -> Self {
+        let mut stars = Stars::default();
+        let mut rest = html;
+
+        while let Some(start) = rest.find("class=\"") {
+            rest = &rest[start + "class=\"".len()..];
+            let Some(end) = rest.find('"') else {
+                break;
+            };
+            let classes: Vec<&str> = rest[..end].split_whitespace().collect();
+            rest = &rest[end..];
+
+            let Some(day) = classes.iter().find_map(|class| {
+                class
+                    .strip_prefix("calendar-day")
+                    .and_then(|day| day.parse().ok())
+                    .and_then(Day::new)
+            }) else {
+                continue;
+            };
+
+            if classes.contains(&"calendar-verycomplete") {
+                stars.record(day, 2);
+            } else if classes.contains(&"calendar-complete") {
+                stars.record(day, 1);
+            }
+        }
+
+        stars
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Stars> for JsonValue {
+    fn from(value: &Stars) -> Self {
+        let map: HashMap<String, JsonValue> = value
+            .data
+            .iter()
+            .map(|(day, stars)| (day.to_string(), JsonValue::Number(f64::from(*stars))))
+            .collect();
+
+        JsonValue::Object(map)
+    }
+}
+
+impl FromStr for Stars {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let json = JsonValue::from_str(s).or(Err("not valid JSON file."))?;
+
+        let days = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let mut data = HashMap::new();
+
+        for (day, stars) in days {
+            let day = Day::from_str(day).or(Err(format!("`{day}` is not a valid day.")))?;
+
+            let stars = stars
+                .get::<f64>()
+                .filter(|stars| [0.0, 1.0, 2.0].contains(*stars))
+                .ok_or(format!("expected stars of day {day} to be 0, 1 or 2."))?;
+
+            data.insert(day, *stars as u8);
+        }
+
+        Ok(Stars { data })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use std::str::FromStr;
+
+    use tinyjson::JsonValue;
+
+    use super::Stars;
+    use crate::day;
+
+    #[test]
+    fn records_stars() {
+        let mut stars = Stars::default();
+        stars.record(day!(1), 1);
+        assert_eq!(stars.get(day!(1)), 1);
+        stars.record(day!(1), 2);
+        stars.record(day!(1), 1);
+        assert_eq!(stars.get(day!(1)), 2);
+        stars.record(day!(3), 2);
+        assert_eq!(stars.get(day!(2)), 0);
+        assert_eq!(stars.total(), 4);
+    }
+
+    #[test]
+    fn parses_calendar() {
+        let html = r#"<pre class="calendar"><a aria-label="Day 1, two stars" href="/2025/day/1" class="calendar-day1 calendar-verycomplete">1</a>
+<a aria-label="Day 2, one star" href="/2025/day/2" class="calendar-day2 calendar-complete">2</a>
+<a aria-label="Day 3" href="/2025/day/3" class="calendar-day3">3</a>
+<span class="calendar-day4">4</span></pre>"#;
+        let stars = Stars::from_calendar(html);
+        assert_eq!(stars.get(day!(1)), 2);
+        assert_eq!(stars.get(day!(2)), 1);
+        assert_eq!(stars.get(day!(3)), 0);
+        assert_eq!(stars.total(), 3);
+    }
+
+    #[test]
+    fn rejects_malformed_stars() {
+        assert!(Stars::from_str(r#"{ "01": 3 }"#).is_err());
+        assert!(Stars::from_str(r#"{ "26": 1 }"#).is_err());
+        assert!(Stars::from_str(r#"{ "01": "2" }"#).is_err());
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        let mut stars = Stars::default();
+        stars.record(day!(1), 2);
+        stars.record(day!(2), 1);
+
+        let json = JsonValue::from(&stars).stringify().unwrap();
+        let parsed = Stars::from_str(&json).unwrap();
+        assert_eq!(parsed.data, stars.data);
+    }
+}
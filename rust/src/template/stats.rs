@@ -0,0 +1,215 @@
+/// Summary statistics over benchmark samples.
+use std::collections::HashMap;
+use std::time::Duration;
+
+use tinyjson::JsonValue;
+
+/// Samples further than this many interquartile ranges outside of the quartiles are outliers.
+const OUTLIER_FENCE: f64 = 1.5;
+
+/// Summary of the samples of a benchmark run, after outliers have been rejected.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct BenchStats {
+    pub min: Duration,
+    pub max: Duration,
+    pub mean: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub stddev: Duration,
+    /// Number of samples the statistics are based on.
+    pub samples: u128,
+    /// Number of samples that were rejected as outliers.
+    pub outliers: u128,
+}
+
+impl BenchStats {
+    /// Computes statistics over the provided samples. Returns `None` if there are no samples.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted: Vec<u128> = samples.iter().map(Duration::as_nanos).collect();
+        sorted.sort_unstable();
+
+        let inliers = reject_outliers(&sorted);
+        let count = inliers.len() as u128;
+
+        let mean = inliers.iter().sum::<u128>() / count;
+
+        #[allow(clippy::cast_precision_loss)]
+        let variance = if inliers.len() > 1 {
+            inliers
+                .iter()
+                .map(|x| (*x as f64 - mean as f64).powi(2))
+                .sum::<f64>()
+                / (inliers.len() - 1) as f64
+        } else {
+            0.0
+        };
+
+        Some(Self {
+            min: nanos(inliers[0]),
+            max: nanos(inliers[inliers.len() - 1]),
+            mean: nanos(mean),
+            median: nanos(percentile(inliers, 50.0)),
+            p95: nanos(percentile(inliers, 95.0)),
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+            samples: count,
+            outliers: (sorted.len() - inliers.len()) as u128,
+        })
+    }
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn nanos(value: u128) -> Duration {
+    Duration::from_nanos(value as u64)
+}
+
+/// Nearest-rank percentile of sorted samples.
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_precision_loss,
+    clippy::cast_sign_loss
+)]
+fn percentile(sorted: &[u128], p: f64) -> u128 {
+    let rank = ((p / 100.0) * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+/// Removes samples outside of Tukey's fences from sorted samples.
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_precision_loss,
+    clippy::cast_sign_loss
+)]
+fn reject_outliers(sorted: &[u128]) -> &[u128] {
+    // quartiles are meaningless for a handful of samples.
+    if sorted.len() < 4 {
+        return sorted;
+    }
+
+    let q1 = percentile(sorted, 25.0) as f64;
+    let q3 = percentile(sorted, 75.0) as f64;
+    let iqr = q3 - q1;
+
+    let lower = q1 - OUTLIER_FENCE * iqr;
+    let upper = q3 + OUTLIER_FENCE * iqr;
+
+    let start = sorted.partition_point(|x| (*x as f64) < lower);
+    let end = sorted.partition_point(|x| (*x as f64) <= upper);
+
+    &sorted[start..end]
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&BenchStats> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &BenchStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        for (key, duration) in [
+            ("min", value.min),
+            ("max", value.max),
+            ("mean", value.mean),
+            ("median", value.median),
+            ("p95", value.p95),
+            ("stddev", value.stddev),
+        ] {
+            map.insert(key.into(), JsonValue::Number(duration.as_nanos() as f64));
+        }
+
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+        map.insert("outliers".into(), JsonValue::Number(value.outliers as f64));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for BenchStats {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected stats to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>())
+                .copied()
+                .ok_or(format!("Expected stats.{key} to be a number."))
+        };
+
+        Ok(BenchStats {
+            min: Duration::from_nanos(number("min")? as u64),
+            max: Duration::from_nanos(number("max")? as u64),
+            mean: Duration::from_nanos(number("mean")? as u64),
+            median: Duration::from_nanos(number("median")? as u64),
+            p95: Duration::from_nanos(number("p95")? as u64),
+            stddev: Duration::from_nanos(number("stddev")? as u64),
+            samples: number("samples")? as u128,
+            outliers: number("outliers")? as u128,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use std::time::Duration;
+
+    use tinyjson::JsonValue;
+
+    use super::BenchStats;
+
+    fn samples(nanos: &[u64]) -> Vec<Duration> {
+        nanos.iter().copied().map(Duration::from_nanos).collect()
+    }
+
+    #[test]
+    fn handles_empty_samples() {
+        assert_eq!(BenchStats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn computes_statistics() {
+        let stats = BenchStats::from_samples(&samples(&[5, 1, 4, 2, 3])).unwrap();
+        assert_eq!(stats.min, Duration::from_nanos(1));
+        assert_eq!(stats.max, Duration::from_nanos(5));
+        assert_eq!(stats.mean, Duration::from_nanos(3));
+        assert_eq!(stats.median, Duration::from_nanos(3));
+        assert_eq!(stats.p95, Duration::from_nanos(5));
+        assert_eq!(stats.stddev, Duration::from_nanos(2));
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn rejects_outliers() {
+        let stats =
+            BenchStats::from_samples(&samples(&[10, 11, 10, 12, 11, 10, 11, 5000])).unwrap();
+        assert_eq!(stats.max, Duration::from_nanos(12));
+        assert_eq!(stats.samples, 7);
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.mean, Duration::from_nanos(10));
+    }
+
+    #[test]
+    fn keeps_few_samples() {
+        let stats = BenchStats::from_samples(&samples(&[10, 5000])).unwrap();
+        assert_eq!(stats.samples, 2);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        let stats = BenchStats::from_samples(&samples(&[5, 1, 4, 2, 3])).unwrap();
+        let json = JsonValue::from(&stats);
+        assert_eq!(BenchStats::try_from(&json).unwrap(), stats);
+    }
+}
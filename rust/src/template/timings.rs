@@ -1,48 +1,293 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{
+    collections::HashMap,
+    fs,
+    io::{self, Error},
+    path::PathBuf,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::part_report::PARSE_PART;
+use crate::template::stats::BenchStats;
+use crate::template::{Day, Year, crate_dir, data_path, git};
 
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+/// Version of the JSON schema of timings files. Files without a version have version 1, which
+/// stored formatted durations, and are migrated when read.
+pub const SCHEMA_VERSION: u64 = 2;
+
+fn get_timings_path(year: Year) -> PathBuf {
+    data_path(year, "timings.json")
+}
+
+/// Earlier versions stored timings in a `data` directory inside the crate, `rust/data/<year>/`.
+/// They are read from there until they are stored to the data directory.
+fn get_legacy_timings_path(year: Year) -> Option<PathBuf> {
+    let path = crate_dir()
+        .join("data")
+        .join(year.to_string())
+        .join("timings.json");
+    (path != get_timings_path(year) && path.is_file()).then_some(path)
+}
+
+/// Represents the benchmark time of a single part, or of the `parse` step.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PartTiming {
+    /// Median duration of the samples.
+    pub duration: Duration,
+    /// Number of times the part ran, including outliers.
+    pub samples: u128,
+    /// Statistics over the samples, if the part was benched.
+    pub stats: Option<BenchStats>,
+    /// Peak heap usage in bytes, if the part was timed with `--dhat`.
+    pub peak_bytes: Option<u64>,
+}
+
+impl PartTiming {
+    /// A part that ran once, e.g. because it was too slow to bench.
+    pub fn once(duration: Duration) -> Self {
+        Self {
+            duration,
+            samples: 1,
+            stats: None,
+            peak_bytes: None,
+        }
+    }
+}
+
+/// Describes how and when a timing was measured.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Metadata {
+    /// Unix timestamp of when the timing was stored, in seconds.
+    pub recorded_at: Option<u64>,
+    /// Git revision the timing was measured at, suffixed with `-dirty` for uncommitted changes.
+    pub revision: Option<String>,
+    /// Cargo profile the solution was built with, `debug`, `release` or `dhat`.
+    pub profile: Option<String>,
+    /// Version of the compiler, e.g. `rustc 1.85.0 (4d91de4e4 2025-02-17)`.
+    pub rustc: Option<String>,
+    /// Model name of the CPU.
+    pub cpu: Option<String>,
+}
+
+impl Metadata {
+    /// Describes timings that are measured now, by solutions built with a profile.
+    pub fn current(profile: &str) -> Self {
+        Self {
+            recorded_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|since| since.as_secs())
+                .ok(),
+            revision: git::current_revision(),
+            profile: Some(profile.into()),
+            rustc: Some(env!("AOC_RUSTC_VERSION"))
+                .filter(|version| !version.is_empty())
+                .map(String::from),
+            cpu: cpu_model(),
+        }
+    }
+}
+
+/// Returns the profile the running binary was built with.
+pub fn current_profile() -> &'static str {
+    if cfg!(feature = "dhat-heap") {
+        "dhat"
+    } else if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    }
+}
+
+/// Returns the model name of the CPU, if the platform exposes it.
+fn cpu_model() -> Option<String> {
+    let model = if cfg!(target_os = "macos") {
+        std::process::Command::new("sysctl")
+            .args(["-n", "machdep.cpu.brand_string"])
+            .output()
+            .ok()
+            .map(|output| String::from_utf8_lossy(&output.stdout).to_string())
+    } else {
+        fs::read_to_string("/proc/cpuinfo")
+            .ok()
+            .and_then(|cpuinfo| {
+                cpuinfo
+                    .lines()
+                    .filter_map(|line| line.split_once(':'))
+                    .find(|(key, _)| key.trim() == "model name")
+                    .map(|(_, model)| model.to_string())
+            })
+    };
+
+    model
+        .map(|model| model.trim().to_string())
+        .filter(|model| !model.is_empty())
+}
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
 pub struct Timing {
     pub day: Day,
-    pub part_1: Option<String>,
-    pub part_2: Option<String>,
+    /// The `parse` step, if the solution has one.
+    pub parse: Option<PartTiming>,
+    pub part_1: Option<PartTiming>,
+    pub part_2: Option<PartTiming>,
     pub total_nanos: f64,
+    pub metadata: Metadata,
+}
+
+impl Timing {
+    /// Returns the timing of a part, or of the `parse` step for [`PARSE_PART`].
+    pub fn part(&self, part: u8) -> Option<&PartTiming> {
+        match part {
+            PARSE_PART => self.parse.as_ref(),
+            1 => self.part_1.as_ref(),
+            2 => self.part_2.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Returns the duration of a part, or of the `parse` step for [`PARSE_PART`].
+    pub fn duration(&self, part: u8) -> Option<Duration> {
+        self.part(part).map(|part| part.duration)
+    }
+
+    /// Returns the peak heap usage of the day, the maximum of its parts.
+    pub fn peak_bytes(&self) -> Option<u64> {
+        [&self.parse, &self.part_1, &self.part_2]
+            .into_iter()
+            .flatten()
+            .filter_map(|part| part.peak_bytes)
+            .max()
+    }
+
+    /// Keeps the peak heap usage of parts of `previous` that this timing did not measure.
+    fn keep_peak_bytes(&mut self, previous: &Timing) {
+        for (part, previous) in [
+            (&mut self.parse, &previous.parse),
+            (&mut self.part_1, &previous.part_1),
+            (&mut self.part_2, &previous.part_2),
+        ] {
+            if let (Some(part), Some(previous)) = (part, previous) {
+                part.peak_bytes = part.peak_bytes.or(previous.peak_bytes);
+            }
+        }
+    }
+
+    /// Takes the peak heap usage of parts that `measured` measured, keeping the durations.
+    fn take_peak_bytes(&mut self, measured: &Timing) {
+        for (part, measured) in [
+            (&mut self.parse, &measured.parse),
+            (&mut self.part_1, &measured.part_1),
+            (&mut self.part_2, &measured.part_2),
+        ] {
+            if let (Some(part), Some(measured)) = (part, measured) {
+                part.peak_bytes = measured.peak_bytes.or(part.peak_bytes);
+            }
+        }
+    }
+}
+
+/// Parses a duration formatted with `{:.1?}`, e.g. `12.3ms`.
+fn parse_duration(formatted: &str) -> Option<Duration> {
+    let unit_start = formatted.find(|c: char| !c.is_ascii_digit() && c != '.')?;
+    let (value, unit) = formatted.split_at(unit_start);
+    let scale = match unit {
+        "ns" => 1e-9,
+        "µs" => 1e-6,
+        "ms" => 1e-3,
+        "s" => 1.0,
+        _ => return None,
+    };
+    Some(Duration::from_secs_f64(value.parse::<f64>().ok()? * scale))
+}
+
+/// Change of the duration of a part of a day against a baseline.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Change {
+    pub day: Day,
+    /// The part, or [`PARSE_PART`] for the `parse` step.
+    pub part: u8,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl Change {
+    /// Returns the change in percent, positive if the part got slower.
+    pub fn percent(&self) -> f64 {
+        let baseline = self.baseline.as_secs_f64();
+        if baseline == 0.0 {
+            return 0.0;
+        }
+        (self.current.as_secs_f64() - baseline) / baseline * 100.0
+    }
 }
 
 /// Represents benchmark times for a set of days.
 /// Can be serialized from / to JSON.
 #[derive(Clone, Debug, Default)]
 pub struct Timings {
+    /// The latest timing of every day.
     pub data: Vec<Timing>,
+    /// Every stored timing, oldest first.
+    pub history: Vec<Timing>,
 }
 
 impl Timings {
-    /// Dehydrate timings to a JSON file.
-    pub fn store_file(&self) -> Result<(), Error> {
+    /// Dehydrate timings to the JSON file of a year.
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
-        json.format_to(&mut file)
+        let path = get_timings_path(year);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = fs::File::create(path)?;
+        json.format_to(&mut file)?;
+
+        // NOTE: the stored timings include the migrated ones, the legacy file is obsolete.
+        if let Some(legacy_path) = get_legacy_timings_path(year) {
+            fs::remove_file(legacy_path)?;
+        }
+        Ok(())
     }
 
-    /// Rehydrate timings from a JSON file. If not present, returns empty timings.
-    pub fn read_from_file() -> Self {
-        fs::read_to_string(TIMINGS_FILE_PATH)
-            .map_err(|x| x.to_string())
-            .and_then(Timings::try_from)
-            .unwrap_or_default()
+    /// Rehydrate timings from the JSON file of a year. If not present, returns empty timings.
+    /// Timings of earlier schema versions or locations are migrated, invalid timings or timings
+    /// of a newer schema version are an error.
+    pub fn read_from_file(year: Year) -> Result<Self, String> {
+        let mut path = get_timings_path(year);
+        if !path.exists()
+            && let Some(legacy_path) = get_legacy_timings_path(year)
+        {
+            eprintln!(
+                "Reading timings from {}, they are moved to {} on the next `--store`.",
+                legacy_path.display(),
+                path.display()
+            );
+            path = legacy_path;
+        }
+
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(format!("{}: {e}", path.display())),
+        };
+
+        Timings::try_from(contents).map_err(|e| format!("{}: {e}", path.display()))
     }
 
-    /// Merge two sets of timings, overwriting `self` with `other` if present.
+    /// Merge two sets of timings, overwriting `self` with `new` if present.
+    /// The timings of `new` are appended to the history. Peak heap usage is only measured with
+    /// `--dhat`, so parts keep their stored peak heap usage if `new` did not measure it.
     pub fn merge(&self, new: &Self) -> Self {
         let mut data: Vec<Timing> = vec![];
 
         for timing in &new.data {
-            data.push(timing.clone());
+            let mut timing = timing.clone();
+            if let Some(stored) = self.data.iter().find(|t| t.day == timing.day) {
+                timing.keep_peak_bytes(stored);
+            }
+            data.push(timing);
         }
 
         for timing in &self.data {
@@ -51,8 +296,104 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
-        Timings { data }
+        data.sort_unstable_by_key(|a| a.day);
+
+        // NOTE: timings stored by earlier versions have no history, start it with their latest.
+        let mut history = if self.history.is_empty() {
+            self.data.clone()
+        } else {
+            self.history.clone()
+        };
+        history.extend(new.data.iter().cloned());
+
+        Timings { data, history }
+    }
+
+    /// Merge the peak heap usage measured by `cargo time --dhat` into `self`. The heap profiler
+    /// slows solutions down, so the stored durations and the history are kept, and days without
+    /// stored timings are skipped.
+    pub fn merge_peak_bytes(&self, new: &Self) -> Self {
+        let mut merged = self.clone();
+        for timing in &mut merged.data {
+            if let Some(measured) = new.data.iter().find(|t| t.day == timing.day) {
+                timing.take_peak_bytes(measured);
+            }
+        }
+        merged
+    }
+
+    /// Returns the stored timing of a day to compare against: its latest run, or its latest run
+    /// at a revision. Runs with uncommitted changes never match a revision. If a profile is given,
+    /// only runs built with that profile match, since timings of different profiles are not
+    /// comparable.
+    pub fn baseline(
+        &self,
+        day: Day,
+        revision: Option<&str>,
+        profile: Option<&str>,
+    ) -> Option<&Timing> {
+        let runs = if self.history.is_empty() {
+            &self.data
+        } else {
+            &self.history
+        };
+
+        runs.iter()
+            .rev()
+            .filter(|timing| timing.day == day)
+            .filter(|timing| {
+                profile.is_none_or(|profile| timing.metadata.profile.as_deref() == Some(profile))
+            })
+            .find(
+                |timing| match (revision, timing.metadata.revision.as_deref()) {
+                    (None, _) => true,
+                    (Some(revision), Some(run)) => {
+                        !run.ends_with("-dirty")
+                            && (run.starts_with(revision) || revision.starts_with(run))
+                    }
+                    (Some(_), None) => false,
+                },
+            )
+    }
+
+    /// Returns the run of a day before its latest one, if the history has one.
+    pub fn previous(&self, day: Day) -> Option<&Timing> {
+        self.history
+            .iter()
+            .rev()
+            .filter(|timing| timing.day == day)
+            .nth(1)
+    }
+
+    /// Compare new timings against their baselines in `self`, see [`Timings::baseline`].
+    pub fn compare(
+        &self,
+        new: &Self,
+        revision: Option<&str>,
+        profile: Option<&str>,
+    ) -> Vec<Change> {
+        let mut changes = vec![];
+
+        for timing in &new.data {
+            let Some(baseline) = self.baseline(timing.day, revision, profile) else {
+                continue;
+            };
+
+            for part in [PARSE_PART, 1, 2] {
+                if let (Some(baseline), Some(current)) =
+                    (baseline.duration(part), timing.duration(part))
+                {
+                    changes.push(Change {
+                        day: timing.day,
+                        part,
+                        baseline,
+                        current,
+                    });
+                }
+            }
+        }
+
+        changes
     }
 
     /// Sum up total duration of timings as millis.
@@ -73,10 +414,16 @@ impl From<Timings> for JsonValue {
     fn from(value: Timings) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
 
+        #[allow(clippy::cast_precision_loss)]
+        map.insert("version".into(), JsonValue::Number(SCHEMA_VERSION as f64));
         map.insert(
             "data".into(),
             JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
         );
+        map.insert(
+            "history".into(),
+            JsonValue::Array(value.history.iter().map(JsonValue::from).collect()),
+        );
 
         JsonValue::Object(map)
     }
@@ -88,18 +435,53 @@ impl TryFrom<String> for Timings {
     fn try_from(value: String) -> Result<Self, Self::Error> {
         let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
 
-        let json_data = json
+        let json = json
             .get::<HashMap<String, JsonValue>>()
-            .ok_or("expected JSON document to be an object.")?
+            .ok_or("expected JSON document to be an object.")?;
+
+        // NOTE: files stored before the schema was versioned are version 1.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let version = match json.get("version") {
+            Some(version) => version
+                .get::<f64>()
+                .map(|version| *version as u64)
+                .ok_or("expected `json.version` to be a number.")?,
+            None => 1,
+        };
+
+        let parse_timing: fn(&JsonValue) -> Result<Timing, String> = match version {
+            1 => Timing::from_v1,
+            SCHEMA_VERSION => |value| Timing::try_from(value),
+            _ => {
+                return Err(format!(
+                    "timings have schema version {version}, but only versions up to {SCHEMA_VERSION} are supported."
+                ));
+            }
+        };
+
+        let json_data = json
             .get("data")
             .ok_or("expected JSON document to have key `data`.")?
             .get::<Vec<JsonValue>>()
             .ok_or("expected `json.data` to be an array.")?;
 
+        // NOTE: the history is optional, timings stored by earlier versions do not have one.
+        let json_history = match json.get("history") {
+            Some(history) => history
+                .get::<Vec<JsonValue>>()
+                .ok_or("expected `json.history` to be an array.")?
+                .as_slice(),
+            None => &[],
+        };
+
         Ok(Timings {
             data: json_data
                 .iter()
-                .map(Timing::try_from)
+                .map(parse_timing)
+                .collect::<Result<_, _>>()?,
+            history: json_history
+                .iter()
+                .map(parse_timing)
                 .collect::<Result<_, _>>()?,
         })
     }
@@ -107,6 +489,60 @@ impl TryFrom<String> for Timings {
 
 /* -------------------------------------------------------------------------- */
 
+impl From<&PartTiming> for JsonValue {
+    fn from(value: &PartTiming) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        #[allow(clippy::cast_precision_loss)]
+        {
+            map.insert(
+                "nanos".into(),
+                JsonValue::Number(value.duration.as_nanos() as f64),
+            );
+            map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+            if let Some(peak_bytes) = value.peak_bytes {
+                map.insert("peak_bytes".into(), JsonValue::Number(peak_bytes as f64));
+            }
+        }
+
+        if let Some(stats) = &value.stats {
+            map.insert("stats".into(), JsonValue::from(stats));
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartTiming {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected part timing to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected part timing.{key} to be a number."))
+        };
+
+        let stats = match json.get("stats") {
+            Some(v) if !v.is_null() => Some(BenchStats::try_from(v)?),
+            _ => None,
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(PartTiming {
+            duration: Duration::from_nanos(number("nanos")? as u64),
+            samples: number("samples")? as u128,
+            stats,
+            // NOTE: the peak heap usage is optional, it is only measured with `--dhat`.
+            peak_bytes: number("peak_bytes").ok().map(|bytes| bytes as u64),
+        })
+    }
+}
+
 impl From<&Timing> for JsonValue {
     fn from(value: &Timing) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
@@ -114,29 +550,77 @@ impl From<&Timing> for JsonValue {
         map.insert("day".into(), JsonValue::String(value.day.to_string()));
         map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
 
-        let part_1 = value.part_1.clone().map(JsonValue::String);
-        let part_2 = value.part_2.clone().map(JsonValue::String);
+        for (key, part) in [
+            ("parse", &value.parse),
+            ("part_1", &value.part_1),
+            ("part_2", &value.part_2),
+        ] {
+            map.insert(
+                key.into(),
+                part.as_ref().map_or(JsonValue::Null, JsonValue::from),
+            );
+        }
 
-        map.insert(
-            "part_1".into(),
-            match part_1 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
-        );
+        let Metadata {
+            recorded_at,
+            revision,
+            profile,
+            rustc,
+            cpu,
+        } = &value.metadata;
 
-        map.insert(
-            "part_2".into(),
-            match part_2 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
-        );
+        if let Some(recorded_at) = recorded_at {
+            #[allow(clippy::cast_precision_loss)]
+            map.insert("recorded_at".into(), JsonValue::Number(*recorded_at as f64));
+        }
+
+        for (key, value) in [
+            ("revision", revision),
+            ("profile", profile),
+            ("rustc", rustc),
+            ("cpu", cpu),
+        ] {
+            if let Some(value) = value {
+                map.insert(key.into(), JsonValue::String(value.clone()));
+            }
+        }
 
         JsonValue::Object(map)
     }
 }
 
+/// Parses the day of a timing.
+fn parse_day(json: &HashMap<String, JsonValue>) -> Result<Day, String> {
+    json.get("day")
+        .and_then(|v| v.get::<String>())
+        .and_then(|day| Day::from_str(day).ok())
+        .ok_or("Expected timing.day to be a Day struct.".into())
+}
+
+/// Parses the total duration of a timing.
+fn parse_total_nanos(json: &HashMap<String, JsonValue>) -> Result<f64, String> {
+    json.get("total_nanos")
+        .and_then(|v| v.get::<f64>().copied())
+        .ok_or("Expected timing.total_nanos to be a number.".into())
+}
+
+/// Parses the metadata of a timing. All of it is optional, as earlier versions stored none.
+fn parse_metadata(json: &HashMap<String, JsonValue>) -> Metadata {
+    let string = |key: &str| json.get(key).and_then(|v| v.get::<String>()).cloned();
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    Metadata {
+        recorded_at: json
+            .get("recorded_at")
+            .and_then(|v| v.get::<f64>())
+            .map(|v| *v as u64),
+        revision: string("revision"),
+        profile: string("profile"),
+        rustc: string("rustc"),
+        cpu: string("cpu"),
+    }
+}
+
 impl TryFrom<&JsonValue> for Timing {
     type Error = String;
 
@@ -145,84 +629,192 @@ impl TryFrom<&JsonValue> for Timing {
             .get::<HashMap<String, JsonValue>>()
             .ok_or("Expected timing to be a JSON object.")?;
 
-        let day = json
-            .get("day")
-            .and_then(|v| v.get::<String>())
-            .and_then(|day| Day::from_str(day).ok())
-            .ok_or("Expected timing.day to be a Day struct.")?;
+        // NOTE: parts are optional, e.g. the parse step of solutions that do not have one.
+        let part = |key: &str| match json.get(key) {
+            Some(v) if !v.is_null() => PartTiming::try_from(v).map(Some),
+            _ => Ok(None),
+        };
 
-        let part_1 = json
-            .get("part_1")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_1 to be null or string.")?;
+        Ok(Timing {
+            day: parse_day(json)?,
+            parse: part("parse")?,
+            part_1: part("part_1")?,
+            part_2: part("part_2")?,
+            total_nanos: parse_total_nanos(json)?,
+            metadata: parse_metadata(json),
+        })
+    }
+}
+
+impl Timing {
+    /// Parses a timing of schema version 1, which stored parts as formatted durations with
+    /// optional statistics in `<part>_stats`.
+    fn from_v1(value: &JsonValue) -> Result<Self, String> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected timing to be a JSON object.")?;
 
-        let part_2 = json
-            .get("part_2")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_2 to be null or string.")?;
+        let part = |key: &str, required: bool| -> Result<Option<PartTiming>, String> {
+            let formatted = match json.get(key) {
+                Some(v) if v.is_null() => return Ok(None),
+                Some(v) => v
+                    .get::<String>()
+                    .ok_or(format!("Expected timing.{key} to be null or string."))?,
+                None if required => {
+                    return Err(format!("Expected timing.{key} to be null or string."));
+                }
+                None => return Ok(None),
+            };
+
+            let stats = match json.get(&format!("{key}_stats")) {
+                Some(v) if !v.is_null() => Some(BenchStats::try_from(v)?),
+                _ => None,
+            };
 
-        let total_nanos = json
-            .get("total_nanos")
-            .and_then(|v| v.get::<f64>().copied())
-            .ok_or("Expected timing.total_nanos to be a number.")?;
+            // NOTE: the formatted duration is the median too, but rounded.
+            let duration = stats
+                .map(|stats| stats.median)
+                .or_else(|| parse_duration(formatted))
+                .ok_or(format!("Expected timing.{key} to be a duration."))?;
+
+            Ok(Some(PartTiming {
+                duration,
+                samples: stats.map_or(1, |stats| stats.samples + stats.outliers),
+                stats,
+                peak_bytes: None,
+            }))
+        };
 
         Ok(Timing {
-            day,
-            part_1: part_1.cloned(),
-            part_2: part_2.cloned(),
-            total_nanos,
+            day: parse_day(json)?,
+            // NOTE: the parse step is optional, most solutions do not have one.
+            parse: part("parse", false)?,
+            part_1: part("part_1", true)?,
+            part_2: part("part_2", true)?,
+            total_nanos: parse_total_nanos(json)?,
+            metadata: parse_metadata(json),
         })
     }
 }
 
 /* -------------------------------------------------------------------------- */
 
-#[cfg(feature = "test_lib")]
+#[cfg(all(test, feature = "test_lib"))]
 mod tests {
+    use std::time::Duration;
+
     use crate::day;
 
-    use super::{Timing, Timings};
+    use super::{Metadata, PartTiming, Timing, Timings};
+
+    fn millis(millis: u64) -> Option<PartTiming> {
+        Some(PartTiming::once(Duration::from_millis(millis)))
+    }
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
                     day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
+                    parse: None,
+                    part_1: millis(10),
+                    part_2: millis(20),
                     total_nanos: 3e+10,
+                    metadata: Metadata::default(),
                 },
                 Timing {
                     day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
+                    parse: None,
+                    part_1: millis(30),
+                    part_2: millis(40),
                     total_nanos: 7e+10,
+                    metadata: Metadata::default(),
                 },
                 Timing {
                     day: day!(4),
-                    part_1: Some("40ms".into()),
+                    parse: None,
+                    part_1: millis(40),
                     part_2: None,
                     total_nanos: 4e+10,
+                    metadata: Metadata::default(),
                 },
             ],
+            history: vec![],
         }
     }
 
     mod deserialization {
+        use std::time::Duration;
+
         use crate::{day, template::timings::Timings};
 
         #[test]
         fn handles_json_timings() {
-            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
+            let json = r#"{ "version": 2, "data": [{ "day": "01", "part_1": { "nanos": 1000000, "samples": 10 }, "part_2": null, "total_nanos": 1000000, "profile": "release", "rustc": "rustc 1.85.0", "cpu": "Test CPU" }] }"#.to_string();
             let timings = Timings::try_from(json).unwrap();
             assert_eq!(timings.data.len(), 1);
             let timing = timings.data.first().unwrap();
             assert_eq!(timing.day, day!(1));
-            assert_eq!(timing.part_1, Some("1ms".to_string()));
+            let part_1 = timing.part_1.unwrap();
+            assert_eq!(part_1.duration, Duration::from_millis(1));
+            assert_eq!(part_1.samples, 10);
+            assert_eq!(part_1.stats, None);
+            assert_eq!(timing.part_2, None);
+            assert_eq!(timing.parse, None);
+            assert_eq!(timing.total_nanos, 1_000_000_f64);
+            assert_eq!(timing.metadata.profile, Some("release".into()));
+            assert_eq!(timing.metadata.rustc, Some("rustc 1.85.0".into()));
+            assert_eq!(timing.metadata.cpu, Some("Test CPU".into()));
+        }
+
+        #[test]
+        fn handles_json_timings_with_stats() {
+            let json = r#"{ "version": 2, "data": [{ "day": "01", "part_1": { "nanos": 2, "samples": 11, "stats": { "min": 1, "max": 3, "mean": 2, "median": 2, "p95": 3, "stddev": 1, "samples": 10, "outliers": 1 } }, "part_2": null, "total_nanos": 2 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            let stats = timing.part_1.unwrap().stats.unwrap();
+            assert_eq!(stats.median.as_nanos(), 2);
+            assert_eq!(stats.p95.as_nanos(), 3);
+            assert_eq!(stats.outliers, 1);
+        }
+
+        #[test]
+        fn migrates_version_1_timings() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1.0ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data.len(), 1);
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.day, day!(1));
+            let part_1 = timing.part_1.unwrap();
+            assert_eq!(part_1.duration, Duration::from_millis(1));
+            assert_eq!(part_1.samples, 1);
             assert_eq!(timing.part_2, None);
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
         }
 
+        #[test]
+        fn migrates_version_1_timings_with_stats() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "2.0ns", "part_2": null, "total_nanos": 2, "part_1_stats": { "min": 1, "max": 3, "mean": 2, "median": 2, "p95": 3, "stddev": 1, "samples": 10, "outliers": 1 } }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            let part_1 = timing.part_1.unwrap();
+            assert_eq!(part_1.duration.as_nanos(), 2);
+            assert_eq!(part_1.samples, 11);
+            assert_eq!(part_1.stats.unwrap().p95.as_nanos(), 3);
+        }
+
+        #[test]
+        fn migrates_version_1_timings_with_parse_and_history() {
+            let json = r#"{ "data": [{ "day": "01", "parse": "3.0ms", "part_1": "1.0ms", "part_2": "2.0ms", "total_nanos": 6000000, "revision": "aaaaaaa" }], "history": [{ "day": "01", "part_1": "1.5ms", "part_2": null, "total_nanos": 1500000, "recorded_at": 1733000000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.parse.unwrap().duration, Duration::from_millis(3));
+            assert_eq!(timing.metadata.revision, Some("aaaaaaa".into()));
+            let run = timings.history.first().unwrap();
+            assert_eq!(run.part_1.unwrap().duration, Duration::from_micros(1500));
+            assert_eq!(run.metadata.recorded_at, Some(1_733_000_000));
+        }
+
         #[test]
         fn handles_empty_timings() {
             let json = r#"{ "data": [] }"#.to_string();
@@ -230,6 +822,12 @@ mod tests {
             assert_eq!(timings.data.len(), 0);
         }
 
+        #[test]
+        fn rejects_newer_versions() {
+            let json = r#"{ "version": 3, "data": [] }"#.to_string();
+            assert!(Timings::try_from(json).unwrap_err().contains("version 3"));
+        }
+
         #[test]
         #[should_panic]
         fn panics_for_invalid_json() {
@@ -243,100 +841,130 @@ mod tests {
             let json = r#"{ "data": [{ "day": "01" }, { "day": "26" }, { "day": "02", "part_2": null, "total_nanos": 0 }] }"#.to_string();
             Timings::try_from(json).unwrap();
         }
+
+        #[test]
+        #[should_panic]
+        fn panics_for_malformed_parts() {
+            let json = r#"{ "version": 2, "data": [{ "day": "01", "part_1": "1.0ms", "total_nanos": 0 }] }"#.to_string();
+            Timings::try_from(json).unwrap();
+        }
     }
 
     mod serialization {
         use super::get_mock_timings;
+        use crate::template::timings::{Metadata, PartTiming, SCHEMA_VERSION, Timings};
         use std::collections::HashMap;
         use tinyjson::JsonValue;
 
         #[test]
         fn serializes_timings() {
             let timings = get_mock_timings();
-            let value = JsonValue::try_from(timings).unwrap();
+            let value = JsonValue::from(timings);
+            let json = value.get::<HashMap<String, JsonValue>>().unwrap();
             assert_eq!(
-                value
-                    .get::<HashMap<String, JsonValue>>()
-                    .unwrap()
-                    .get("data")
+                json.get("data")
                     .unwrap()
                     .get::<Vec<JsonValue>>()
                     .unwrap()
                     .len(),
                 3
             );
+            #[allow(clippy::cast_precision_loss)]
+            let version = SCHEMA_VERSION as f64;
+            assert_eq!(json.get("version").unwrap().get::<f64>(), Some(&version));
+        }
+
+        #[test]
+        fn round_trips_timings() {
+            let mut timings = get_mock_timings();
+            timings.data[0].metadata = Metadata {
+                recorded_at: Some(1_733_000_000),
+                revision: Some("aaaaaaa".into()),
+                profile: Some("release".into()),
+                rustc: Some("rustc 1.85.0".into()),
+                cpu: Some("Test CPU".into()),
+            };
+            timings.data[1].part_1 = timings.data[1].part_1.map(|part| PartTiming {
+                peak_bytes: Some(4_096),
+                ..part
+            });
+
+            let json = JsonValue::from(timings.clone()).stringify().unwrap();
+            let parsed = Timings::try_from(json).unwrap();
+            assert_eq!(parsed.data.len(), 3);
+            assert_eq!(parsed.data[0].part_1, timings.data[0].part_1);
+            assert_eq!(parsed.data[1].part_1, timings.data[1].part_1);
+            assert_eq!(parsed.data[0].metadata, timings.data[0].metadata);
+            assert_eq!(parsed.data[2].part_2, None);
         }
     }
 
     mod is_day_complete {
         use crate::{
             day,
-            template::timings::{Timing, Timings},
+            template::timings::{Metadata, Timing, Timings},
         };
 
-        #[test]
-        fn handles_completed_days() {
-            let timings = Timings {
+        use super::millis;
+
+        fn timings(part_1: Option<u64>, part_2: Option<u64>) -> Timings {
+            Timings {
                 data: vec![Timing {
                     day: day!(1),
-                    part_1: Some("1ms".into()),
-                    part_2: Some("2ms".into()),
-                    total_nanos: 3_000_000_000_f64,
+                    parse: None,
+                    part_1: part_1.and_then(millis),
+                    part_2: part_2.and_then(millis),
+                    total_nanos: 0.0,
+                    metadata: Metadata::default(),
                 }],
-            };
+                history: vec![],
+            }
+        }
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+        #[test]
+        fn handles_completed_days() {
+            assert!(timings(Some(1), Some(2)).is_day_complete(day!(1)));
         }
 
         #[test]
         fn handles_partial_days() {
-            let timings = Timings {
-                data: vec![Timing {
-                    day: day!(1),
-                    part_1: Some("1ms".into()),
-                    part_2: None,
-                    total_nanos: 1_000_000_000_f64,
-                }],
-            };
-
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert!(!timings(Some(1), None).is_day_complete(day!(1)));
         }
 
         #[test]
         fn handles_uncompleted_days() {
-            let timings = Timings {
-                data: vec![Timing {
-                    day: day!(1),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0.0,
-                }],
-            };
-
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert!(!timings(None, None).is_day_complete(day!(1)));
         }
     }
 
     mod merge {
+        use std::time::Duration;
+
         use crate::{
             day,
-            template::timings::{Timing, Timings},
+            template::timings::{Metadata, PartTiming, Timing, Timings},
         };
 
-        use super::get_mock_timings;
+        use super::{get_mock_timings, millis};
 
-        #[test]
-        fn handles_disjunct_timings() {
-            let timings = get_mock_timings();
-            let other = Timings {
+        fn empty(day: crate::template::Day) -> Timings {
+            Timings {
                 data: vec![Timing {
-                    day: day!(3),
+                    day,
+                    parse: None,
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
+                    metadata: Metadata::default(),
                 }],
-            };
-            let merged = timings.merge(&other);
+                history: vec![],
+            }
+        }
+
+        #[test]
+        fn handles_disjunct_timings() {
+            let timings = get_mock_timings();
+            let merged = timings.merge(&empty(day!(3)));
             assert_eq!(merged.data.len(), 4);
             assert_eq!(merged.data[0].day, day!(1));
             assert_eq!(merged.data[1].day, day!(2));
@@ -347,16 +975,7 @@ mod tests {
         #[test]
         fn handles_overlapping_timings() {
             let timings = get_mock_timings();
-
-            let other = Timings {
-                data: vec![Timing {
-                    day: day!(2),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0_f64,
-                }],
-            };
-            let merged = timings.merge(&other);
+            let merged = timings.merge(&empty(day!(2)));
 
             assert_eq!(merged.data.len(), 3);
             assert_eq!(merged.data[0].day, day!(1));
@@ -380,5 +999,264 @@ mod tests {
             let merged = timings.merge(&other);
             assert_eq!(merged.data.len(), 3);
         }
+
+        #[test]
+        fn keeps_stored_peak_bytes() {
+            let mut timings = get_mock_timings();
+            timings.data[0].part_1 = timings.data[0].part_1.map(|part| PartTiming {
+                peak_bytes: Some(2_048),
+                ..part
+            });
+
+            let merged = timings.merge(&Timings {
+                data: vec![Timing {
+                    part_1: millis(5),
+                    part_2: millis(6),
+                    ..timings.data[0].clone()
+                }],
+                history: vec![],
+            });
+            assert_eq!(merged.data[0].duration(1), Some(Duration::from_millis(5)));
+            assert_eq!(merged.data[0].peak_bytes(), Some(2_048));
+
+            let remeasured = merged.merge(&Timings {
+                data: vec![Timing {
+                    part_1: Some(PartTiming {
+                        peak_bytes: Some(1_024),
+                        ..PartTiming::once(Duration::from_millis(5))
+                    }),
+                    ..merged.data[0].clone()
+                }],
+                history: vec![],
+            });
+            assert_eq!(remeasured.data[0].peak_bytes(), Some(1_024));
+        }
+
+        #[test]
+        fn merges_only_peak_bytes() {
+            let timings = get_mock_timings();
+
+            let merged = timings.merge_peak_bytes(&Timings {
+                data: vec![
+                    Timing {
+                        part_1: Some(PartTiming {
+                            peak_bytes: Some(4_096),
+                            ..PartTiming::once(Duration::from_secs(1))
+                        }),
+                        ..timings.data[0].clone()
+                    },
+                    Timing {
+                        day: day!(9),
+                        ..timings.data[0].clone()
+                    },
+                ],
+                history: vec![],
+            });
+            assert_eq!(merged.data.len(), timings.data.len());
+            assert_eq!(merged.data[0].peak_bytes(), Some(4_096));
+            assert_eq!(merged.data[0].duration(1), timings.data[0].duration(1));
+            assert_eq!(merged.history.len(), timings.history.len());
+        }
+    }
+
+    mod files {
+        use std::fs;
+
+        use crate::template::mock_server::{MockServer, Sandbox};
+        use crate::{day, template::timings::Timings, year};
+
+        use super::get_mock_timings;
+
+        #[test]
+        fn migrates_timings_stored_inside_the_crate() {
+            let sandbox = Sandbox::new(&MockServer::builder().start());
+            let legacy_path = sandbox.path("rust/data/2025/timings.json");
+            fs::create_dir_all(legacy_path.parent().unwrap()).unwrap();
+            get_mock_timings().store_file(year!(2025)).unwrap();
+            fs::rename(sandbox.path("data/2025/timings.json"), &legacy_path).unwrap();
+
+            let timings = Timings::read_from_file(year!(2025)).unwrap();
+            assert_eq!(timings.data.len(), 3);
+            assert_eq!(timings.data[0].day, day!(1));
+
+            timings.store_file(year!(2025)).unwrap();
+            assert!(sandbox.exists("data/2025/timings.json"));
+            assert!(!legacy_path.exists());
+        }
+
+        #[test]
+        fn rejects_invalid_timings() {
+            let sandbox = Sandbox::new(&MockServer::builder().start());
+            fs::create_dir_all(sandbox.path("data/2025")).unwrap();
+            fs::write(
+                sandbox.path("data/2025/timings.json"),
+                "{ \"version\": 99 }",
+            )
+            .unwrap();
+
+            assert!(Timings::read_from_file(year!(2025)).is_err());
+            assert!(
+                Timings::read_from_file(year!(2024))
+                    .unwrap()
+                    .data
+                    .is_empty()
+            );
+        }
+    }
+
+    mod history {
+        use std::time::Duration;
+
+        use crate::{
+            day,
+            template::{
+                part_report::PARSE_PART,
+                timings::{Metadata, Timing, Timings},
+            },
+        };
+
+        use super::{get_mock_timings, millis};
+
+        fn run(part_1: u64, revision: &str) -> Timing {
+            Timing {
+                day: day!(1),
+                parse: millis(1),
+                part_1: millis(part_1),
+                part_2: None,
+                total_nanos: 0_f64,
+                metadata: Metadata {
+                    recorded_at: Some(1_733_000_000),
+                    revision: Some(revision.into()),
+                    profile: Some("release".into()),
+                    ..Metadata::default()
+                },
+            }
+        }
+
+        #[test]
+        fn appends_merged_timings() {
+            let first = get_mock_timings().merge(&Timings {
+                data: vec![run(10, "aaaaaaa")],
+                history: vec![],
+            });
+            // NOTE: the history starts with the timings stored before it existed.
+            assert_eq!(first.history.len(), 4);
+
+            let second = first.merge(&Timings {
+                data: vec![run(12, "bbbbbbb")],
+                history: vec![],
+            });
+            assert_eq!(second.data.len(), 3);
+            assert_eq!(second.data[0].part_1, millis(12));
+            assert_eq!(second.history.len(), 5);
+            assert_eq!(second.history[4].metadata.revision, Some("bbbbbbb".into()));
+        }
+
+        #[test]
+        fn round_trips_history() {
+            let timings = Timings {
+                data: vec![run(12, "bbbbbbb")],
+                history: vec![run(10, "aaaaaaa"), run(12, "bbbbbbb")],
+            };
+            let json = tinyjson::JsonValue::from(timings).stringify().unwrap();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.history.len(), 2);
+            assert_eq!(timings.history[0].metadata.recorded_at, Some(1_733_000_000));
+            assert_eq!(timings.history[0].metadata.revision, Some("aaaaaaa".into()));
+        }
+
+        #[test]
+        fn finds_previous_runs() {
+            let timings = Timings {
+                data: vec![run(12, "bbbbbbb")],
+                history: vec![run(10, "aaaaaaa"), run(12, "bbbbbbb")],
+            };
+            let previous = timings.previous(day!(1)).unwrap();
+            assert_eq!(previous.metadata.revision, Some("aaaaaaa".into()));
+            assert!(timings.previous(day!(2)).is_none());
+
+            let first_run = Timings {
+                data: vec![run(12, "bbbbbbb")],
+                history: vec![run(12, "bbbbbbb")],
+            };
+            assert!(first_run.previous(day!(1)).is_none());
+        }
+
+        #[test]
+        fn finds_baselines() {
+            let timings = Timings {
+                data: vec![run(12, "ccccccc-dirty")],
+                history: vec![
+                    run(10, "aaaaaaa"),
+                    run(11, "bbbbbbb"),
+                    run(12, "ccccccc-dirty"),
+                ],
+            };
+            let part_1 = |revision| {
+                timings
+                    .baseline(day!(1), revision, None)
+                    .and_then(|timing| timing.part_1)
+            };
+
+            assert_eq!(part_1(None), millis(12));
+            assert_eq!(part_1(Some("aaaa")), millis(10));
+            assert_eq!(part_1(Some("bbbbbbbbb")), millis(11));
+            assert_eq!(part_1(Some("ccccccc")), None);
+            assert!(timings.baseline(day!(2), None, None).is_none());
+        }
+
+        #[test]
+        fn compares_against_baselines() {
+            let stored = Timings {
+                data: vec![run(10, "aaaaaaa")],
+                history: vec![],
+            };
+            let new = Timings {
+                data: vec![run(12, "bbbbbbb")],
+                history: vec![],
+            };
+
+            let changes = stored.compare(&new, None, None);
+            assert_eq!(changes.len(), 2);
+            assert_eq!(changes[0].part, PARSE_PART);
+            assert!(changes[0].percent().abs() < 1e-9);
+            assert_eq!(changes[1].part, 1);
+            assert_eq!(changes[1].baseline, Duration::from_millis(10));
+            assert!((changes[1].percent() - 20.0).abs() < 1e-9);
+
+            assert!(stored.compare(&new, Some("bbbbbbb"), None).is_empty());
+        }
+
+        #[test]
+        fn compares_only_matching_profiles() {
+            let debug = Timing {
+                metadata: Metadata {
+                    profile: Some("debug".into()),
+                    ..run(50, "bbbbbbb").metadata
+                },
+                ..run(50, "bbbbbbb")
+            };
+            let stored = Timings {
+                data: vec![debug.clone()],
+                history: vec![run(10, "aaaaaaa"), debug],
+            };
+            let baseline = |profile| {
+                stored
+                    .baseline(day!(1), None, profile)
+                    .and_then(|timing| timing.part_1)
+            };
+
+            assert_eq!(baseline(None), millis(50));
+            assert_eq!(baseline(Some("debug")), millis(50));
+            assert_eq!(baseline(Some("release")), millis(10));
+            assert_eq!(baseline(Some("dhat")), None);
+
+            let new = Timings {
+                data: vec![run(12, "ccccccc")],
+                history: vec![],
+            };
+            let changes = stored.compare(&new, None, Some("release"));
+            assert_eq!(changes[1].baseline, Duration::from_millis(10));
+        }
     }
 }
@@ -0,0 +1,162 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::template::AllDays;
+
+/// The year of the first advent.
+const FIRST_YEAR: u16 = 2015;
+
+/// The first year in which advent was shortened to 12 puzzles.
+const FIRST_SHORT_YEAR: u16 = 2025;
+
+/// A valid year of advent (i.e. an integer starting at 2015).
+///
+/// # Display
+/// This value displays as a four digit number.
+///
+/// ```
+/// # use advent_of_code::template::Year;
+/// let year = Year::new(2025).unwrap();
+/// assert_eq!(year.to_string(), "2025")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// The year configured via `AOC_YEAR` at compile time, if set and valid.
+    pub const COMPILED: Option<Self> = match option_env!("AOC_YEAR") {
+        Some(year) => match parse_u16(year) {
+            Some(year) => Self::new(year),
+            None => None,
+        },
+        None => None,
+    };
+
+    /// Creates a [`Year`] from the provided value if it's in the valid range,
+    /// returns [`None`] otherwise.
+    pub const fn new(year: u16) -> Option<Self> {
+        if year < FIRST_YEAR {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    /// Returns the year configured via the `AOC_YEAR` environment variable, if set.
+    pub fn from_env() -> Option<Self> {
+        std::env::var("AOC_YEAR").ok()?.parse().ok()
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+
+    /// Returns the number of puzzle days in this year, i.e. the last valid day number.
+    #[must_use]
+    pub const fn last_day(self) -> u8 {
+        if self.0 >= FIRST_SHORT_YEAR { 12 } else { 25 }
+    }
+
+    /// Returns an iterator over every day of advent in this year.
+    pub fn days(self) -> AllDays {
+        AllDays::new(self)
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// `u16::from_str` is not `const`, so `AOC_YEAR` has to be parsed by hand at compile time.
+const fn parse_u16(s: &str) -> Option<u16> {
+    let bytes = s.as_bytes();
+    if bytes.is_empty() {
+        return None;
+    }
+
+    let mut value: u16 = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            return None;
+        }
+        value = match value.checked_mul(10) {
+            Some(value) => match value.checked_add((bytes[i] - b'0') as u16) {
+                Some(value) => value,
+                None => return None,
+            },
+            None => return None,
+        };
+        i += 1;
+    }
+
+    Some(value)
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "expecting a year starting at {FIRST_YEAR}")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Year`] value in a const context.
+#[macro_export]
+macro_rules! year {
+    ($year:expr) => {
+        const {
+            $crate::template::Year::new($year)
+                .expect("invalid year, expecting a value starting at 2015")
+        }
+    };
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{Year, parse_u16};
+
+    #[test]
+    fn parses_years() {
+        assert_eq!("2024".parse::<Year>().ok(), Year::new(2024));
+        assert!("2014".parse::<Year>().is_err());
+        assert!("twenty".parse::<Year>().is_err());
+    }
+
+    #[test]
+    fn parses_years_in_const_context() {
+        assert_eq!(parse_u16("2025"), Some(2025));
+        assert_eq!(parse_u16(""), None);
+        assert_eq!(parse_u16("20x5"), None);
+        assert_eq!(parse_u16("99999"), None);
+    }
+
+    #[test]
+    fn last_day_shortened_in_2025() {
+        assert_eq!(Year(2015).last_day(), 25);
+        assert_eq!(Year(2024).last_day(), 25);
+        assert_eq!(Year(2025).last_day(), 12);
+    }
+}
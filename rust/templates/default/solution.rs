@@ -0,0 +1,14 @@
+//! # %TITLE%
+//!
+//! <https://adventofcode.com/%YEAR%/day/%DAY_NUMBER%>
+advent_of_code::solution!(%DAY_NUMBER%, year = %YEAR%);
+
+pub fn part_one(input: &str) -> Option<u64> {
+    None
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    None
+}
+
+advent_of_code::example_tests!();
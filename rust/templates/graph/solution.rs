@@ -0,0 +1,27 @@
+//! # %TITLE%
+//!
+//! <https://adventofcode.com/%YEAR%/day/%DAY_NUMBER%>
+use std::collections::HashMap;
+
+advent_of_code::solution!(%DAY_NUMBER%, year = %YEAR%, parse = parse);
+
+/// The edges of every node, from lines like `aaa: bbb ccc`.
+pub type Graph<'a> = HashMap<&'a str, Vec<&'a str>>;
+
+pub fn parse(input: &str) -> Graph<'_> {
+    input
+        .lines()
+        .filter_map(|line| line.split_once(':'))
+        .map(|(node, edges)| (node.trim(), edges.split_whitespace().collect()))
+        .collect()
+}
+
+pub fn part_one(graph: &Graph) -> Option<u64> {
+    None
+}
+
+pub fn part_two(graph: &Graph) -> Option<u64> {
+    None
+}
+
+advent_of_code::example_tests!();
@@ -0,0 +1,54 @@
+//! # %TITLE%
+//!
+//! <https://adventofcode.com/%YEAR%/day/%DAY_NUMBER%>
+advent_of_code::solution!(%DAY_NUMBER%, year = %YEAR%, parse = parse);
+
+/// The input as a grid of bytes, indexed by `(x, y)` from the top left.
+pub struct Grid {
+    cells: Vec<Vec<u8>>,
+    width: usize,
+    height: usize,
+}
+
+impl Grid {
+    /// Returns the cell at a position, or `None` outside of the grid.
+    pub fn get(&self, (x, y): (isize, isize)) -> Option<u8> {
+        let (x, y) = (usize::try_from(x).ok()?, usize::try_from(y).ok()?);
+        self.cells.get(y)?.get(x).copied()
+    }
+
+    /// Returns the positions of all cells.
+    pub fn positions(&self) -> impl Iterator<Item = (isize, isize)> + use<'_> {
+        (0..self.height as isize).flat_map(|y| (0..self.width as isize).map(move |x| (x, y)))
+    }
+
+    /// Returns the positions of the orthogonal neighbours of a position inside the grid.
+    pub fn neighbours(
+        &self,
+        (x, y): (isize, isize),
+    ) -> impl Iterator<Item = (isize, isize)> + use<'_> {
+        [(0, -1), (1, 0), (0, 1), (-1, 0)]
+            .into_iter()
+            .map(move |(dx, dy)| (x + dx, y + dy))
+            .filter(|&position| self.get(position).is_some())
+    }
+}
+
+pub fn parse(input: &str) -> Grid {
+    let cells: Vec<Vec<u8>> = input.lines().map(|line| line.bytes().collect()).collect();
+    Grid {
+        width: cells.first().map_or(0, Vec::len),
+        height: cells.len(),
+        cells,
+    }
+}
+
+pub fn part_one(grid: &Grid) -> Option<u64> {
+    None
+}
+
+pub fn part_two(grid: &Grid) -> Option<u64> {
+    None
+}
+
+advent_of_code::example_tests!();
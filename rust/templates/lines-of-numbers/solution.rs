@@ -0,0 +1,27 @@
+//! # %TITLE%
+//!
+//! <https://adventofcode.com/%YEAR%/day/%DAY_NUMBER%>
+advent_of_code::solution!(%DAY_NUMBER%, year = %YEAR%, parse = parse);
+
+/// The numbers on every line of the input, e.g. `7 6 -4 2 1` or `3,4`.
+pub fn parse(input: &str) -> Vec<Vec<i64>> {
+    input
+        .lines()
+        .map(|line| {
+            line.split(|c: char| !(c.is_ascii_digit() || c == '-'))
+                .filter(|number| !number.is_empty())
+                .map(|number| number.parse().unwrap())
+                .collect()
+        })
+        .collect()
+}
+
+pub fn part_one(lines: &[Vec<i64>]) -> Option<u64> {
+    None
+}
+
+pub fn part_two(lines: &[Vec<i64>]) -> Option<u64> {
+    None
+}
+
+advent_of_code::example_tests!();
@@ -0,0 +1,21 @@
+//! # %TITLE%
+//!
+//! <https://adventofcode.com/%YEAR%/day/%DAY_NUMBER%>
+advent_of_code::solution!(%DAY_NUMBER%, year = %YEAR%, parse = parse);
+
+/// The input, parsed once and shared by both parts.
+pub struct Input {}
+
+pub fn parse(input: &str) -> Input {
+    Input {}
+}
+
+pub fn part_one(input: &Input) -> Option<u64> {
+    None
+}
+
+pub fn part_two(input: &Input) -> Option<u64> {
+    None
+}
+
+advent_of_code::example_tests!();
@@ -15,7 +15,7 @@ if (proc.status) {
 
 const fileMemory = new WebAssembly.Memory({ initial: 1 });
 const view = new Int32Array(fileMemory.buffer);
-const file = await fs.readFile("../data/inputs/06.txt");
+const file = await fs.readFile("../data/2025/inputs/06.txt");
 file.copy(view);
 
 const wasmModule = await WebAssembly.instantiate(proc.stdout, {
@@ -137,7 +137,7 @@ pub fn main() !void {
 }
 
 fn read_file(allocator: std.mem.Allocator, kind: []const u8) !std.fs.File {
-    const input_path = try std.fs.path.join(allocator, &[_][]const u8{ "../data", kind, "02.txt" });
+    const input_path = try std.fs.path.join(allocator, &[_][]const u8{ "../data/2025", kind, "02.txt" });
     defer allocator.free(input_path);
 
     return try std.fs.cwd().openFile(input_path, .{ .mode = .read_only });